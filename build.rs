@@ -4,24 +4,67 @@ use std::env;
 
 use std::io::Write;
 
-const MIN_DIMS: usize = 2;
+const MIN_DIMS: usize = 1;
 const MIN_ORDER: usize = 1;
 
-const MAX_DIMS: usize = 4;
-const MAX_ORDER: usize = 6;
+/// The default highest number of dimensions points and vectors get generated for, overridable with
+/// the `BEV_MAX_DIMS` environment variable.
+const DEFAULT_MAX_DIMS: usize = 4;
+/// The highest number of dimensions that can be generated.
+const DIMS_LIMIT: usize = 16;
+/// The default highest order curves get generated for, overridable with the `BEV_MAX_ORDER`
+/// environment variable.
+const DEFAULT_MAX_ORDER: usize = 6;
+/// The highest order that can be generated. Past this, the binomial coefficients approach the largest
+/// integers that an `f64` can exactly represent.
+const ORDER_LIMIT: usize = 50;
+
+/// Read a limit on the generated types from the first of the environment variables in `vars` that's
+/// set, clamping it to `default` through `limit`. Limits can only be raised from the default, as the
+/// crate itself uses the default types.
+fn env_limit(vars: &[&str], default: usize, limit: usize) -> usize {
+    for var in vars {
+        println!("cargo:rerun-if-env-changed={}", var);
+    }
+
+    let (var, value) = match vars.iter().filter_map(|var| env::var(var).ok().map(|value| (var, value))).next() {
+        Some(set) => set,
+        None => return default
+    };
+    let value: usize = value.trim().parse().unwrap_or_else(|_| panic!("{} must be a number, not \"{}\"", var, value));
+    if !(default..=limit).contains(&value) {
+        println!("cargo:warning={} is {}, which is outside of {} to {}; clamping it", var, value, default, limit);
+    }
+    value.clamp(default, limit)
+}
+
+/// Get the name of the field for the `dim`th dimension, counting from zero. The first four dimensions
+/// are `x`, `y`, `z`, and `w`, and the ones after are named after their number, starting with `d5`.
+fn dim_tag(dim: usize) -> String {
+    match ["x", "y", "z", "w"].get(dim) {
+        Some(tag) => tag.to_string(),
+        None => format!("d{}", dim + 1)
+    }
+}
 
 fn main() {
     let out = env::var("OUT_DIR").unwrap();
     let mut file = File::create(&Path::new(&out).join("macro_invocs.rs")).unwrap();
 
-    let dim_tags = ['x', 'y', 'z', 'w'];
+    // `NBEZ_MAX_ORDER` is still read, for builds that configured it before it was renamed.
+    let max_order = env_limit(&["BEV_MAX_ORDER", "NBEZ_MAX_ORDER"], DEFAULT_MAX_ORDER, ORDER_LIMIT);
+    let max_dims = env_limit(&["BEV_MAX_DIMS"], DEFAULT_MAX_DIMS, DIMS_LIMIT);
+
+    // Make sure the coefficients match the known sixth row of Pascal's triangle.
+    let row: Vec<u64> = (0..7).map(|k| combination(6, k)).collect();
+    assert_eq!(&[1, 6, 15, 20, 15, 6, 1], &row[..]);
 
     // Create points and vectors
-    for dim in MIN_DIMS..(MAX_DIMS + 1) {
+    for dim in MIN_DIMS..(max_dims + 1) {
         writeln!(file, "n_pointvector!{{\"{0}-dimensional point\", \"{0}-dimensional vector\", {0}; Point{0}d, Vector{0}d {{", dim).unwrap();
 
-        for (i, dt) in dim_tags[0..dim].iter().enumerate() {
-            write!(file, "    {}", dt).unwrap();
+        for i in 0..dim {
+            write!(file, "    {}", dim_tag(i)).unwrap();
 
             if i == dim - 1 {
                 writeln!(file, "")
@@ -34,7 +77,7 @@ fn main() {
     }
 
     // Create one-dimensional bezier polynomials
-    for order in MIN_ORDER..(MAX_ORDER + 1) {
+    for order in MIN_ORDER..(max_order + 1) {
         writeln!(file, "n_bezier!{{\"Order {0} bezier curve\", {0}, {1}; Bez{0}o {{", order, sum(order)).unwrap();
         for o in 0..(order + 1) {
             write!(file, "    {}: {}", get_param_name(o, order), combination(order, o)).unwrap();
@@ -64,12 +107,22 @@ fn main() {
         }
         writeln!(file, "    {};", get_param_name(order, order)).unwrap();
 
-        if order == MAX_ORDER {
+        if order == max_order {
             writeln!(file, "}} elevated NBez<F, P, [P; {}]> }}", order + 2).unwrap();
         } else {
             writeln!(file, "}} elevated Bez{}o<F, P> }}", order + 1).unwrap();
         }
     }
+
+    let mut file = File::create(Path::new(&out).join("max_order.rs")).unwrap();
+    writeln!(file, "/// The highest order of the generated curves.").unwrap();
+    writeln!(file, "const MAX_ORDER: usize = {};", max_order).unwrap();
+    writeln!(file, "/// The highest order generated curve.").unwrap();
+    writeln!(file, "type MaxOrderBez<F, P> = Bez{}o<F, P>;", max_order).unwrap();
+    writeln!(file, "/// The highest number of dimensions of the generated points.").unwrap();
+    writeln!(file, "const MAX_DIMS: usize = {};", max_dims).unwrap();
+    writeln!(file, "/// The generated point with the most dimensions.").unwrap();
+    writeln!(file, "type MaxDimsPoint<F> = Point{}d<F>;", max_dims).unwrap();
 }
 
 fn get_param_name(param_number: usize, poly_order: usize) -> String {
@@ -84,15 +137,19 @@ fn get_param_name(param_number: usize, poly_order: usize) -> String {
     }
 }
 
-fn combination(n: usize, k: usize) -> usize {
-    factorial(n) / (factorial(k) * factorial(n - k))
-}
+/// Get the binomial coefficient `n` choose `k`, with the multiplicative formula. Each intermediate
+/// result is itself a binomial coefficient, so this only overflows if the result does, in which case
+/// it panics.
+fn combination(n: usize, k: usize) -> u64 {
+    let k = k.min(n - k) as u128;
+    let n = n as u128;
 
-fn factorial(n: usize) -> usize {
-    match n {
-        0 => 1,
-        _ => n * factorial(n-1)
+    let mut acc: u128 = 1;
+    for i in 0..k {
+        acc = acc * (n - i) / (i + 1);
     }
+    assert!(acc <= u64::MAX as u128, "binomial coefficient {} choose {} overflows", n, k);
+    acc as u64
 }
 
 /// Get the sum of all numbers on the interval [0, n]
@@ -0,0 +1,108 @@
+use alloc::vec::Vec;
+
+use super::{BezChain, BezCurve, OrderStatic, Point2d, Float};
+use super::binomial;
+
+/// Multiply the one-dimensional bezier polynomials with the coefficients `a` and `b`, getting the
+/// coefficients of their product.
+pub(crate) fn mul<F: Float>(a: &[F], b: &[F]) -> Vec<F> {
+    let (n, m) = (a.len() - 1, b.len() - 1);
+    let mut product = vec![F::zero(); n + m + 1];
+
+    for (i, &ai) in a.iter().enumerate() {
+        for (j, &bj) in b.iter().enumerate() {
+            product[i + j] = product[i + j] + ai * bj * binomial(n, i) * binomial(m, j);
+        }
+    }
+    for (k, p) in product.iter_mut().enumerate() {
+        *p = *p / binomial(n + m, k);
+    }
+    product
+}
+
+/// Get the coefficients of the derivative of the one-dimensional bezier polynomial with the
+/// coefficients `c`.
+pub(crate) fn derivative<F: Float>(c: &[F]) -> Vec<F> {
+    if c.len() < 2 {
+        return vec![F::zero()];
+    }
+
+    let order = F::from_usize(c.len() - 1).unwrap();
+    c.windows(2).map(|w| (w[1] - w[0]) * order).collect()
+}
+
+/// Integrate the one-dimensional bezier polynomial with the coefficients `c` over `0.0` to `1.0`. Every
+/// basis polynomial integrates to the same value, so this is the average of the coefficients.
+fn integral<F: Float>(c: &[F]) -> F {
+    c.iter().fold(F::zero(), |acc, c| acc + *c) / F::from_usize(c.len()).unwrap()
+}
+
+/// Get the contributions of the curve described by `points` to the signed area of a closed shape and
+/// to the shape's first moments of area about the y and x axes. These are the line integrals of
+/// `(x dy - y dx) / 2`, `x² dy / 2`, and `-y² dx / 2` along the curve. By Green's theorem, summing
+/// these over all of a closed shape's outline gives its area and moments.
+pub fn area_integrals<F: Float>(points: &[Point2d<F>]) -> (F, F, F) {
+    let two = F::from_f32(2.0).unwrap();
+    let x: Vec<F> = points.iter().map(|p| p.x).collect();
+    let y: Vec<F> = points.iter().map(|p| p.y).collect();
+    let (dx, dy) = (derivative(&x), derivative(&y));
+
+    let x_dy = mul(&x, &dy);
+    let y_dx = mul(&y, &dx);
+    (
+        (integral(&x_dy) - integral(&y_dx)) / two,
+        integral(&mul(&x, &x_dy)) / two,
+        -integral(&mul(&y, &y_dx)) / two
+    )
+}
+
+impl<F, B, C> BezChain<F, B, C>
+        where F: Float,
+              B: BezCurve<F, Point = Point2d<F>> + OrderStatic,
+              C: AsRef<[Point2d<F>]> {
+    /// Sum the area integrals over the whole chain, closing it with a straight line if it isn't
+    /// already closed.
+    fn chain_area_integrals(&self) -> (F, F, F) {
+        let count = self.segment_count();
+        if count == 0 {
+            return (F::zero(), F::zero(), F::zero());
+        }
+
+        let points = self.as_ref().as_ref();
+        let closing = area_integrals(&[points[count * B::order_static()], points[0]]);
+        self.iter().fold(closing, |acc, curve| {
+            let integrals = area_integrals(AsRef::<[Point2d<F>]>::as_ref(&curve));
+            (acc.0 + integrals.0, acc.1 + integrals.1, acc.2 + integrals.2)
+        })
+    }
+
+    /// Get the signed area enclosed by the chain, which is positive if the chain goes around the area
+    /// counter-clockwise and negative if it goes clockwise. If the end of the chain isn't its start,
+    /// the chain is closed with a straight line between them.
+    ///
+    /// This is calculated exactly from the curves' control points with Green's theorem, so parts of
+    /// the outline that wind around the same area multiple times count multiple times.
+    pub fn signed_area(&self) -> F {
+        self.chain_area_integrals().0
+    }
+
+    /// Get the centroid of the area enclosed by the chain, closing the chain in the same way as
+    /// `signed_area`. Returns `None` if the area is too close to zero for the centroid to be
+    /// meaningful, relative to the size of the chain's control points.
+    pub fn centroid(&self) -> Option<Point2d<F>> {
+        let (area, moment_x, moment_y) = self.chain_area_integrals();
+
+        // Compare the area against the area of the control points' bounding box.
+        let points = self.as_ref().as_ref();
+        let start = *points.first()?;
+        let (min, max) = points.iter().fold((start, start), |(min, max), p| {
+            (min.min(*p), max.max(*p))
+        });
+        let extent = (max.x - min.x).max(max.y - min.y);
+        if area.abs() <= extent * extent * F::epsilon().sqrt() {
+            return None;
+        }
+
+        Some(Point2d::new(moment_x / area, moment_y / area))
+    }
+}
@@ -0,0 +1,51 @@
+use core::marker::PhantomData;
+use alloc::vec::Vec;
+
+use super::{BezChain, Bez3o, Point, Float, lerp};
+
+/// Builds a chain of cubic curves one curve at a time, starting from a single point and extending the
+/// chain from its current end, in the same way as path APIs in graphics libraries.
+#[derive(Debug, Clone)]
+pub struct ChainBuilder<F, P>
+        where F: Float,
+              P: Point<F> {
+    points: Vec<P>,
+    phantom: PhantomData<F>
+}
+
+impl<F, P> ChainBuilder<F, P>
+        where F: Float,
+              P: Point<F> {
+    /// Create a new builder for a chain that starts at `start`.
+    pub fn new(start: P) -> ChainBuilder<F, P> {
+        ChainBuilder {
+            points: vec![start],
+            phantom: PhantomData
+        }
+    }
+
+    /// Get the current end of the chain being built.
+    pub fn current(&self) -> P {
+        self.points[self.points.len() - 1]
+    }
+
+    /// Add a cubic curve from the current end of the chain to `end`, with the control points `ctrl0`
+    /// and `ctrl1`.
+    pub fn cubic_to(&mut self, ctrl0: P, ctrl1: P, end: P) -> &mut ChainBuilder<F, P> {
+        self.points.extend_from_slice(&[ctrl0, ctrl1, end]);
+        self
+    }
+
+    /// Add a straight line from the current end of the chain to `end`. The line is a cubic curve with
+    /// its control points evenly spaced along it, so that the line is traced at a constant speed.
+    pub fn line_to(&mut self, end: P) -> &mut ChainBuilder<F, P> {
+        let start = self.current();
+        let third = F::one() / F::from_u32(3).unwrap();
+        self.cubic_to(lerp(start, end, third), lerp(start, end, third + third), end)
+    }
+
+    /// Finish building, getting the chain of all added curves.
+    pub fn build(self) -> BezChain<F, Bez3o<F, P>, Vec<P>> {
+        BezChain::from_container(self.points)
+    }
+}
@@ -0,0 +1,58 @@
+use alloc::vec::Vec;
+
+use super::{BezChain, Bez3o, Point, Float, lerp, point_distance};
+
+impl<F, P, C> BezChain<F, Bez3o<F, P>, C>
+        where F: Float,
+              P: Point<F>,
+              C: AsRef<[P]> {
+    /// Get the chain's points, without any trailing points that don't form a full curve.
+    fn full_curve_points(&self) -> &[P] {
+        let points = self.as_ref().as_ref();
+        let end = if points.is_empty() {0} else {points.len() - (points.len() - 1) % 3};
+        &points[..end]
+    }
+
+    /// Get a copy of this chain that ends where it starts, adding a straight curve from the end of the
+    /// chain back to its start if they aren't already the same point.
+    pub fn closed(&self) -> BezChain<F, Bez3o<F, P>, Vec<P>> {
+        let mut points = self.full_curve_points().to_vec();
+        if points.len() > 1 && !self.is_closed(F::zero()) {
+            let (start, end) = (points[0], points[points.len() - 1]);
+            let third = F::one() / F::from_u32(3).unwrap();
+            points.push(lerp(end, start, third));
+            points.push(lerp(end, start, third + third));
+            points.push(start);
+        }
+        BezChain::from_container(points)
+    }
+
+    /// Like `closed`, but the added curve leaves the end of the chain and enters its start tangent to
+    /// the curves there, so the chain stays smooth through the join. Handles of zero length don't
+    /// have a direction, so the added curve is straight at those ends.
+    pub fn closed_smooth(&self) -> BezChain<F, Bez3o<F, P>, Vec<P>> {
+        let mut points = self.full_curve_points().to_vec();
+        if points.len() < 4 || self.is_closed(F::zero()) {
+            return BezChain::from_container(points);
+        }
+
+        let third = F::one() / F::from_u32(3).unwrap();
+        let (start, end) = (points[0], points[points.len() - 1]);
+        let handle_len = point_distance(start, end) * third;
+
+        // Continue the handle from `anchor` to `handle` through the anchor, `handle_len` long.
+        let continue_handle = |anchor: P, handle: P, straight: P| {
+            let len = point_distance(handle, anchor);
+            if len == F::zero() {
+                straight
+            } else {
+                anchor + (anchor - handle) * (handle_len / len)
+            }
+        };
+
+        let ctrl0 = continue_handle(end, points[points.len() - 2], lerp(end, start, third));
+        let ctrl1 = continue_handle(start, points[1], lerp(end, start, third + third));
+        points.extend_from_slice(&[ctrl0, ctrl1, start]);
+        BezChain::from_container(points)
+    }
+}
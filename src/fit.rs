@@ -0,0 +1,233 @@
+use alloc::vec::Vec;
+
+use super::{BezChain, BezCurve, Bez3o, Point2d, Vector2d, Float};
+
+/// The maximum number of times the points get split while fitting.
+const MAX_DEPTH: u32 = 32;
+/// The number of times the points' parameters get refined before giving up and splitting the points.
+/// Chord length is only a rough guess at each point's parameter, so refining it is usually enough to
+/// get a curve that could be fit with one cubic within tolerance.
+const MAX_ITERATIONS: u32 = 4;
+/// The number of places each curve gets sampled at when checking whether curves can be merged.
+const SIMPLIFY_SAMPLES: usize = 16;
+
+/// Estimate the direction the curve leaves `points[0]` in from the next point that's distinct from it.
+fn end_tangent<F: Float>(points: &[Point2d<F>]) -> Vector2d<F> {
+    let next = points[1..].iter().find(|p| **p != points[0]).unwrap_or(&points[0]);
+    normalize(Vector2d::from(*next - points[0]))
+}
+
+/// Normalize `v`, leaving it unchanged if it has a length of zero.
+fn normalize<F: Float>(v: Vector2d<F>) -> Vector2d<F> {
+    let len = v.len();
+    if len == F::zero() {v} else {v / len}
+}
+
+/// Assign each point a parameter based on its distance along the polyline through the points.
+fn chord_length_params<F: Float>(points: &[Point2d<F>]) -> Vec<F> {
+    let mut params = Vec::with_capacity(points.len());
+    let mut len = F::zero();
+    params.push(len);
+    for w in points.windows(2) {
+        len = len + w[0].distance(w[1]);
+        params.push(len);
+    }
+
+    if len != F::zero() {
+        for param in &mut params {
+            *param = *param / len;
+        }
+    }
+    params
+}
+
+/// Create the cubic that starts and ends at the ends of `points`, leaving and entering along `tan0` and
+/// `tan1`, with handles a third of the distance between the ends.
+fn heuristic_cubic<F: Float>(points: &[Point2d<F>], tan0: Vector2d<F>, tan1: Vector2d<F>) -> Bez3o<F, Point2d<F>> {
+    let start = points[0];
+    let end = points[points.len() - 1];
+    let dist = start.distance(end) / F::from_u32(3).unwrap();
+    Bez3o::new(start, start + tan0 * dist, end + tan1 * dist, end)
+}
+
+/// Find the cubic through the ends of `points` whose handles lie along `tan0` and `tan1` and which
+/// minimizes the squared distance between the points and the curve at `params`.
+fn least_squares_cubic<F: Float>(points: &[Point2d<F>], params: &[F], tan0: Vector2d<F>, tan1: Vector2d<F>) -> Bez3o<F, Point2d<F>> {
+    let start = points[0];
+    let end = points[points.len() - 1];
+    let three = F::from_u32(3).unwrap();
+
+    let (mut c00, mut c01, mut c11, mut x0, mut x1) = (F::zero(), F::zero(), F::zero(), F::zero(), F::zero());
+    for (point, &u) in points.iter().zip(params.iter()) {
+        let u1 = F::one() - u;
+        let (b0, b1, b2, b3) = (u1 * u1 * u1, three * u * u1 * u1, three * u * u * u1, u * u * u);
+        let a0 = tan0 * b1;
+        let a1 = tan1 * b2;
+
+        c00 = c00 + a0.dot(a0);
+        c01 = c01 + a0.dot(a1);
+        c11 = c11 + a1.dot(a1);
+
+        let fixed = Vector2d::from(*point - (start * (b0 + b1) + end * (b2 + b3)));
+        x0 = x0 + a0.dot(fixed);
+        x1 = x1 + a1.dot(fixed);
+    }
+
+    let det = c00 * c11 - c01 * c01;
+    let (alpha0, alpha1) = if det == F::zero() {
+        (F::zero(), F::zero())
+    } else {
+        ((x0 * c11 - x1 * c01) / det, (c00 * x1 - c01 * x0) / det)
+    };
+
+    // Handles that are tiny or point backwards make for degenerate curves, so fall back to the
+    // heuristic instead.
+    let min_alpha = start.distance(end) * F::epsilon().sqrt();
+    if alpha0 <= min_alpha || alpha1 <= min_alpha {
+        heuristic_cubic(points, tan0, tan1)
+    } else {
+        Bez3o::new(start, start + tan0 * alpha0, end + tan1 * alpha1, end)
+    }
+}
+
+/// Get the largest distance between a point and the curve at the point's parameter, along with the
+/// index of that point.
+fn max_error<F: Float>(curve: &Bez3o<F, Point2d<F>>, points: &[Point2d<F>], params: &[F]) -> (F, usize) {
+    let mut max = (F::zero(), points.len() / 2);
+    for (i, (point, &u)) in points.iter().zip(params.iter()).enumerate() {
+        let dist = point.distance(curve.interp_unbounded(u));
+        if dist > max.0 {
+            max = (dist, i);
+        }
+    }
+    max
+}
+
+/// Move each parameter closer to the parameter of the closest point on the curve with a Newton step.
+fn reparameterize<F: Float>(curve: &Bez3o<F, Point2d<F>>, points: &[Point2d<F>], params: &mut [F]) {
+    for (point, u) in points.iter().zip(params.iter_mut()) {
+        let diff = Vector2d::from(curve.interp_unbounded(*u) - *point);
+        let d1 = curve.slope_unbounded(*u);
+        let d2 = curve.slope2_unbounded(*u);
+
+        let numer = diff.dot(d1);
+        let denom = d1.dot(d1) + diff.dot(d2);
+        if denom != F::zero() {
+            *u = (*u - numer / denom).max(F::zero()).min(F::one());
+        }
+    }
+}
+
+/// Fit a single cubic to `points`, refining the points' parameters until the cubic is within
+/// `tolerance` or `MAX_ITERATIONS` is reached. Returns the cubic along with its error and the index of
+/// the point that error was measured at.
+fn fit_single<F: Float>(points: &[Point2d<F>], tan0: Vector2d<F>, tan1: Vector2d<F>, tolerance: F) -> (Bez3o<F, Point2d<F>>, F, usize) {
+    let mut params = chord_length_params(points);
+    let mut curve = least_squares_cubic(points, &params, tan0, tan1);
+    let (mut error, mut split) = max_error(&curve, points, &params);
+
+    let mut iteration = 0;
+    while tolerance < error && iteration < MAX_ITERATIONS {
+        reparameterize(&curve, points, &mut params);
+        curve = least_squares_cubic(points, &params, tan0, tan1);
+        let (new_error, new_split) = max_error(&curve, points, &params);
+        error = new_error;
+        split = new_split;
+        iteration += 1;
+    }
+    (curve, error, split)
+}
+
+fn fit_points<F: Float>(points: &[Point2d<F>], tan0: Vector2d<F>, tan1: Vector2d<F>, tolerance: F, depth: u32, out: &mut Vec<Bez3o<F, Point2d<F>>>) {
+    if points.len() == 2 {
+        out.push(heuristic_cubic(points, tan0, tan1));
+        return;
+    }
+
+    let (curve, error, split) = fit_single(points, tan0, tan1, tolerance);
+    if error <= tolerance || depth >= MAX_DEPTH {
+        out.push(curve);
+        return;
+    }
+
+    // Split at the worst point, keeping the curve smooth through it.
+    let split = split.max(1).min(points.len() - 2);
+    let center = normalize(Vector2d::from(points[split - 1] - points[split + 1]));
+    fit_points(&points[..split + 1], tan0, center, tolerance, depth + 1, out);
+    fit_points(&points[split..], -center, tan1, tolerance, depth + 1, out);
+}
+
+/// Fit a chain of cubic curves through `points`, in order, such that every point is within `tolerance`
+/// of the chain. Consecutive curves share end points and are tangent to each other.
+///
+/// This uses Schneider's algorithm: the points are assigned parameters by their distance along the
+/// polyline through them, and a cubic is fit to those parameters with least squares, with the
+/// parameters then refined with Newton's method. If that cubic isn't within `tolerance`, the points
+/// are split at the point farthest from it and each half is fit separately. Returns an empty `Vec` if
+/// there are fewer than two points.
+pub fn fit_cubic<F: Float>(points: &[Point2d<F>], tolerance: F) -> Vec<Bez3o<F, Point2d<F>>> {
+    let mut curves = Vec::new();
+    if points.len() < 2 {
+        return curves;
+    }
+
+    let mut reversed = points.to_vec();
+    reversed.reverse();
+    let tan0 = end_tangent(points);
+    let tan1 = end_tangent(&reversed);
+
+    fit_points(points, tan0, tan1, tolerance, 0, &mut curves);
+    curves
+}
+
+/// Get the direction `curve` leaves its start in, from the first handle that's distinct from the start.
+fn handle_tangent<F: Float>(curve: &Bez3o<F, Point2d<F>>) -> Vector2d<F> {
+    end_tangent(&[curve.start, curve.ctrl0, curve.ctrl1, curve.end])
+}
+
+/// Try to replace the consecutive `curves` with one cubic that's within `tolerance` of all of them.
+fn merge_curves<F: Float>(curves: &[Bez3o<F, Point2d<F>>], tolerance: F) -> Option<Bez3o<F, Point2d<F>>> {
+    let mut points = vec![curves[0].start];
+    for curve in curves {
+        points.extend(curve.samples(SIMPLIFY_SAMPLES).skip(1));
+    }
+
+    let last = curves[curves.len() - 1];
+    let tan0 = handle_tangent(&curves[0]);
+    let tan1 = handle_tangent(&Bez3o::new(last.end, last.ctrl1, last.ctrl0, last.start));
+
+    let (curve, error, _) = fit_single(&points, tan0, tan1, tolerance);
+    if error <= tolerance {Some(curve)} else {None}
+}
+
+impl<F, C> BezChain<F, Bez3o<F, Point2d<F>>, C>
+        where F: Float,
+              C: AsRef<[Point2d<F>]> {
+    /// Create a chain with fewer curves that stays within `tolerance` of this one, by merging runs of
+    /// adjacent curves that a single cubic can approximate. The start and end of the chain, along with
+    /// the points where the remaining curves meet, are left exactly where they were, and every merged
+    /// curve leaves and enters in the same directions as the curves it replaces.
+    pub fn simplify(&self, tolerance: F) -> BezChain<F, Bez3o<F, Point2d<F>>, Vec<Point2d<F>>> {
+        let curves: Vec<_> = self.iter().collect();
+        let mut points: Vec<Point2d<F>> = self.as_ref().as_ref().iter().take(1).cloned().collect();
+
+        let mut start = 0;
+        while start < curves.len() {
+            // Greedily grow the run of merged curves for as long as it stays within tolerance.
+            let mut merged = curves[start];
+            let mut end = start + 1;
+            while end < curves.len() {
+                match merge_curves(&curves[start..end + 1], tolerance) {
+                    Some(curve) => merged = curve,
+                    None => break
+                }
+                end += 1;
+            }
+
+            points.extend_from_slice(&[merged.ctrl0, merged.ctrl1, merged.end]);
+            start = end;
+        }
+
+        BezChain::from_container(points)
+    }
+}
@@ -0,0 +1,37 @@
+use alloc::vec::Vec;
+
+use super::{Point2d, Vector2d, Float};
+
+/// Get the vertices of the convex hull of `points` in counter-clockwise order, using Andrew's monotone
+/// chain algorithm. The hull starts at the leftmost point, with ties broken by the lowest. Points lying
+/// on the hull's edges aren't included, so if all of the points are collinear, only the two extreme
+/// points are returned.
+pub fn convex_hull<F: Float>(points: &[Point2d<F>]) -> Vec<Point2d<F>> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| (a.x, a.y).partial_cmp(&(b.x, b.y)).unwrap());
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    // Whether going from `a` to `b` to `c` makes a counter-clockwise turn.
+    let ccw = |a: Point2d<F>, b: Point2d<F>, c: Point2d<F>| {
+        Vector2d::from(b - a).cross(Vector2d::from(c - a)) > F::zero()
+    };
+
+    let mut hull: Vec<Point2d<F>> = Vec::with_capacity(sorted.len() + 1);
+    // Build the lower hull from left to right, then the upper hull from right to left.
+    for pass in 0..2 {
+        let start = hull.len();
+        for i in 0..sorted.len() {
+            let p = if pass == 0 {sorted[i]} else {sorted[sorted.len() - 1 - i]};
+            while hull.len() >= start + 2 && !ccw(hull[hull.len() - 2], hull[hull.len() - 1], p) {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        // The last point of each half is the first point of the other.
+        hull.pop();
+    }
+    hull
+}
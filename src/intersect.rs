@@ -0,0 +1,417 @@
+use alloc::vec::Vec;
+
+use super::{BezCurve, Float, Point, PVOps, Point2d, Vector2d, dot, lerp, decasteljau_split};
+
+/// The maximum number of times the pair of curves gets subdivided while searching for intersections.
+/// Pieces that overlap along a stretch get caught before they reach this, so it's only a last resort
+/// that keeps the search from subdividing forever. Sharing it between both curves, this still leaves
+/// room for pieces smaller than `1e-9` of each curve.
+const MAX_DEPTH: u32 = 64;
+
+/// If clipping a piece keeps more than this fraction of it, the clipping isn't converging quickly
+/// enough, and one of the pieces gets split in half instead.
+const MAX_CLIP_RATIO: f32 = 0.8;
+
+/// A piece of one of the curves being intersected, along with the range of the original curve's `t`
+/// that it covers.
+struct Piece<F, P> {
+    points: Vec<P>,
+    t0: F,
+    t1: F
+}
+
+impl<F: Float, P: Point<F>> Piece<F, P> {
+    /// Get the minimum and maximum corners of the box containing the piece's control points, which
+    /// contains the piece itself.
+    fn bounds(&self) -> (P, P) {
+        let start = self.points[0];
+        self.points[1..].iter().fold((start, start), |(min, max), &p| (min.min(p), max.max(p)))
+    }
+
+    /// Get the part of the piece between `lo` and `hi`, which are relative to the piece rather than
+    /// to the original curve.
+    fn clip(&self, lo: F, hi: F) -> Piece<F, P> {
+        let (_, rest) = decasteljau_split(&self.points, lo);
+        // The rest of the piece covers `lo` to `1.0`, so `hi` has to be rescaled onto it.
+        let rest_t = if lo == F::one() {F::zero()} else {(hi - lo) / (F::one() - lo)};
+        let (points, _) = decasteljau_split(&rest, rest_t);
+
+        let range = self.t1 - self.t0;
+        Piece {points, t0: self.t0 + range * lo, t1: self.t0 + range * hi}
+    }
+
+    fn split(&self) -> (Piece<F, P>, Piece<F, P>) {
+        let half = F::from_f32(0.5).unwrap();
+        let (left, right) = decasteljau_split(&self.points, half);
+        let mid = (self.t0 + self.t1) * half;
+        (Piece {points: left, t0: self.t0, t1: mid}, Piece {points: right, t0: mid, t1: self.t1})
+    }
+}
+
+/// The intersections found while searching a pair of curves, as the `t` on each curve.
+struct Hits<F> {
+    /// Places where the curves cross.
+    points: Vec<(F, F)>,
+    /// Stretches along which the curves overlap, as the places at both ends of each stretch. Overlaps
+    /// get found one pair of pieces at a time, so a single stretch is made up of many of these.
+    overlaps: Vec<[(F, F); 2]>
+}
+
+/// Get the largest side length of the box with the corners `min` and `max`.
+fn extent<F: Float>(min: &[F], max: &[F]) -> F {
+    min.iter().zip(max.iter()).fold(F::zero(), |acc, (lo, hi)| acc.max(*hi - *lo))
+}
+
+/// Get the length of the point or vector `v`.
+fn len<F: Float, V: PVOps<F>>(v: V) -> F {
+    dot(v.as_slice(), v.as_slice()).sqrt()
+}
+
+/// Get how far along the line from `start` to `end` the point on it closest to `p` is, as a fraction
+/// of the distance between them. If `start` and `end` are the same point, this is zero.
+fn line_param<F: Float, P: Point<F>>(start: P, end: P, p: P) -> F {
+    let dir = end - start;
+    let len_sq = dot(dir.as_slice(), dir.as_slice());
+    if len_sq == F::zero() {
+        F::zero()
+    } else {
+        let offset = p - start;
+        dot(offset.as_slice(), dir.as_slice()) / len_sq
+    }
+}
+
+/// Get the distance of `p` from the line through `start` and `end`.
+fn line_dist<F: Float, P: Point<F>>(start: P, end: P, p: P) -> F {
+    len(p - lerp(start, end, line_param(start, end, p)))
+}
+
+/// If the pieces `a` and `b` are both flat and lie along the same line, get the places at both ends of
+/// the stretch where they overlap. Subdividing such pieces any further would only find more and more
+/// hits along the same stretch, so the search stops there instead.
+fn overlap_ends<F, P>(a: &Piece<F, P>, b: &Piece<F, P>, tolerance: F) -> Option<[(F, F); 2]>
+        where F: Float,
+              P: Point<F> {
+    let (a0, a1) = (a.points[0], a.points[a.points.len() - 1]);
+    let (b0, b1) = (b.points[0], b.points[b.points.len() - 1]);
+
+    // Every control point of both pieces has to be within `tolerance` of the line through either
+    // piece's ends, which is when both of their fat lines are about as thin as `tolerance`.
+    let on_line = |start, end| a.points.iter().chain(&b.points).all(|&p| line_dist(start, end, p) <= tolerance);
+    if !on_line(a0, a1) || !on_line(b0, b1) {
+        return None;
+    }
+
+    let clamp = |u: F| u.max(F::zero()).min(F::one());
+    let (u0, u1) = (line_param(a0, a1, b0), line_param(a0, a1, b1));
+    let (lo, hi) = (clamp(u0.min(u1)), clamp(u0.max(u1)));
+    if lo > hi {
+        return None;
+    }
+
+    // Flat pieces are very nearly parametrized by how far along their line a point is.
+    let hit = |u: F| {
+        let ub = clamp(line_param(b0, b1, lerp(a0, a1, u)));
+        (a.t0 + (a.t1 - a.t0) * u, b.t0 + (b.t1 - b.t0) * ub)
+    };
+    Some([hit(lo), hit(hi)])
+}
+
+fn find_intersections<F, P>(a: Piece<F, P>, b: Piece<F, P>, tolerance: F, depth: u32, hits: &mut Hits<F>, steps: &mut usize)
+        where F: Float,
+              P: Point<F> {
+    *steps += 1;
+    let (a_min, a_max) = a.bounds();
+    let (b_min, b_max) = b.bounds();
+
+    let overlap = a_min.as_slice().iter().zip(a_max.as_slice()).zip(b_min.as_slice().iter().zip(b_max.as_slice()))
+        .all(|((a_lo, a_hi), (b_lo, b_hi))| a_lo <= b_hi && b_lo <= a_hi);
+    if !overlap {
+        return;
+    }
+
+    let a_extent = extent(a_min.as_slice(), a_max.as_slice());
+    let b_extent = extent(b_min.as_slice(), b_max.as_slice());
+    if (a_extent < tolerance && b_extent < tolerance) || depth >= MAX_DEPTH {
+        let half = F::from_f32(0.5).unwrap();
+        hits.points.push(((a.t0 + a.t1) * half, (b.t0 + b.t1) * half));
+    } else if let Some(ends) = overlap_ends(&a, &b, tolerance) {
+        hits.overlaps.push(ends);
+    } else if a_extent >= b_extent {
+        let (left, right) = a.split();
+        find_intersections(left, Piece {points: b.points.clone(), ..b}, tolerance, depth + 1, hits, steps);
+        find_intersections(right, b, tolerance, depth + 1, hits, steps);
+    } else {
+        let (left, right) = b.split();
+        find_intersections(Piece {points: a.points.clone(), ..a}, left, tolerance, depth + 1, hits, steps);
+        find_intersections(a, right, tolerance, depth + 1, hits, steps);
+    }
+}
+
+/// Get the fat line of the curve described by `points`, as the unit direction from its start to its
+/// end along with the smallest and largest signed distances of its control points from the line
+/// through its ends. Distances to the left of the direction are positive. If the curve ends where it
+/// starts, there's no line, so the direction and both distances are zero.
+pub(crate) fn fat_line<F: Float>(points: &[Point2d<F>]) -> (Vector2d<F>, F, F) {
+    let start = points[0];
+    let dir = Vector2d::from(points[points.len() - 1] - start);
+    let len = dir.len();
+    if len == F::zero() {
+        return (dir, F::zero(), F::zero());
+    }
+
+    let dir = dir / len;
+    let normal = dir.perp();
+    let (dmin, dmax) = points.iter().fold((F::zero(), F::zero()), |(lo, hi), p| {
+        let d = normal.dot(Vector2d::from(*p - start));
+        (lo.min(d), hi.max(d))
+    });
+    (dir, dmin, dmax)
+}
+
+/// Get the range of `t` on the curve described by `points` that can be within the fat line of the
+/// curve described by `other`, which is the narrowest band parallel to the line through `other`'s ends
+/// that contains all of `other`'s control points. Returns `None` if no part of the curve can be within
+/// the band.
+///
+/// The curve's distance from the line is itself a bezier polynomial, so its part within the band is
+/// bounded by the part of its control polygon's convex hull within the band. The band is widened by
+/// `tolerance` on both sides, so that an intersection right at the edge of the range doesn't get
+/// clipped off by rounding errors.
+fn clip_range<F: Float>(points: &[Point2d<F>], other: &[Point2d<F>], tolerance: F) -> Option<(F, F)> {
+    let start = other[0];
+    let (dir, dmin, dmax) = fat_line(other);
+    let (dmin, dmax) = (dmin - tolerance, dmax + tolerance);
+    if dir.len() == F::zero() {
+        // Without a line to clip against, nothing can be clipped off.
+        return Some((F::zero(), F::one()));
+    }
+
+    let normal = dir.perp();
+    let distance = |p: Point2d<F>| normal.dot(Vector2d::from(p - start));
+
+    let order = F::from_usize(points.len().max(2) - 1).unwrap();
+    let coeffs: Vec<(F, F)> = points.iter().enumerate()
+        .map(|(i, p)| (F::from_usize(i).unwrap() / order, distance(*p)))
+        .collect();
+
+    // The hull's part within the band has vertices at control points within the band and at places
+    // where the hull's edges cross the band's edges. Every hull edge is a line between two control
+    // points, so checking all of those lines finds all of the vertices.
+    let (mut lo, mut hi) = (F::infinity(), F::neg_infinity());
+    for (i, &(ti, di)) in coeffs.iter().enumerate() {
+        if dmin <= di && di <= dmax {
+            lo = lo.min(ti);
+            hi = hi.max(ti);
+        }
+        for &(tj, dj) in &coeffs[i + 1..] {
+            for &bound in &[dmin, dmax] {
+                if (di - bound) * (dj - bound) < F::zero() {
+                    let t = ti + (tj - ti) * (bound - di) / (dj - di);
+                    lo = lo.min(t);
+                    hi = hi.max(t);
+                }
+            }
+        }
+    }
+
+    if lo <= hi {
+        Some((lo.max(F::zero()), hi.min(F::one())))
+    } else {
+        None
+    }
+}
+
+/// Search for intersections by clipping `a` against the fat line of `b`, then continuing with the
+/// roles swapped. `swapped` is whether `a` and `b` are swapped from the original curves.
+fn clip_intersections<F>(a: Piece<F, Point2d<F>>, b: Piece<F, Point2d<F>>, tolerance: F, depth: u32, swapped: bool, hits: &mut Hits<F>, steps: &mut usize)
+        where F: Float {
+    *steps += 1;
+    let (a_min, a_max) = a.bounds();
+    let (b_min, b_max) = b.bounds();
+
+    let overlap = a_min.as_slice().iter().zip(a_max.as_slice()).zip(b_min.as_slice().iter().zip(b_max.as_slice()))
+        .all(|((a_lo, a_hi), (b_lo, b_hi))| a_lo <= b_hi && b_lo <= a_hi);
+    if !overlap {
+        return;
+    }
+
+    let a_extent = extent(a_min.as_slice(), a_max.as_slice());
+    let b_extent = extent(b_min.as_slice(), b_max.as_slice());
+    if (a_extent < tolerance && b_extent < tolerance) || depth >= MAX_DEPTH {
+        let half = F::from_f32(0.5).unwrap();
+        let (ta, tb) = ((a.t0 + a.t1) * half, (b.t0 + b.t1) * half);
+        hits.points.push(if swapped {(tb, ta)} else {(ta, tb)});
+        return;
+    }
+    if let Some([start, end]) = overlap_ends(&a, &b, tolerance) {
+        hits.overlaps.push(if swapped {[(start.1, start.0), (end.1, end.0)]} else {[start, end]});
+        return;
+    }
+
+    let (lo, hi) = match clip_range(&a.points, &b.points, tolerance) {
+        Some(range) => range,
+        None => return
+    };
+
+    if hi - lo <= F::from_f32(MAX_CLIP_RATIO).unwrap() {
+        clip_intersections(b, a.clip(lo, hi), tolerance, depth + 1, !swapped, hits, steps);
+    } else if a_extent >= b_extent {
+        // There's likely more than one intersection, so split them up.
+        let (left, right) = a.split();
+        clip_intersections(Piece {points: b.points.clone(), ..b}, left, tolerance, depth + 1, !swapped, hits, steps);
+        clip_intersections(b, right, tolerance, depth + 1, !swapped, hits, steps);
+    } else {
+        let (left, right) = b.split();
+        clip_intersections(left, Piece {points: a.points.clone(), ..a}, tolerance, depth + 1, !swapped, hits, steps);
+        clip_intersections(right, a, tolerance, depth + 1, !swapped, hits, steps);
+    }
+}
+
+/// A hit along with where it is on both curves and how fast the curves are moving there, which is what
+/// telling whether two hits are the same intersection takes.
+struct Hit<F, P> {
+    ta: F,
+    tb: F,
+    a: P,
+    b: P,
+    speed_a: F,
+    speed_b: F
+}
+
+impl<F: Float, P: Point<F>> Hit<F, P> {
+    fn new<A, B>(a: &A, b: &B, (ta, tb): (F, F)) -> Hit<F, P>
+            where A: BezCurve<F, Point = P>,
+                  B: BezCurve<F, Point = P> {
+        Hit {
+            ta,
+            tb,
+            a: a.interp_unbounded(ta),
+            b: b.interp_unbounded(tb),
+            speed_a: len(a.slope_unbounded(ta)),
+            speed_b: len(b.slope_unbounded(tb))
+        }
+    }
+
+    /// Get whether this hit and `other` are within `dist` of each other along both curves. Being at
+    /// the same point isn't enough, as a curve that loops back on itself passes through its loop point
+    /// at two different `t`s, so the hits' `t`s also have to be close, measured by how far the curve
+    /// moves between them.
+    fn near(&self, other: &Hit<F, P>, dist: F) -> bool {
+        let near_on = |point: P, other_point: P, dt: F, speed: F| len(point - other_point) <= dist && dt.abs() * speed <= dist;
+        near_on(self.a, other.a, self.ta - other.ta, self.speed_a) && near_on(self.b, other.b, self.tb - other.tb, self.speed_b)
+    }
+}
+
+/// Join the overlaps in `hits` that continue one another into whole stretches, then sort the hits by
+/// the `t` on `a` and merge hits that are close enough together to be the same intersection. A
+/// stretch gets reported as hits at both of its ends.
+fn merge_hits<F, P, A, B>(a: &A, b: &B, hits: Hits<F>, tolerance: F) -> Vec<(F, F)>
+        where F: Float,
+              P: Point<F>,
+              A: BezCurve<F, Point = P>,
+              B: BezCurve<F, Point = P> {
+    let merge_dist = tolerance * F::from_f32(4.0).unwrap();
+    let mut points = hits.points;
+
+    let mut overlaps: Vec<[(F, F); 2]> = hits.overlaps.into_iter().map(|[start, end]| if start <= end {[start, end]} else {[end, start]}).collect();
+    overlaps.sort_by(|x, y| x[0].partial_cmp(&y[0]).unwrap());
+    let mut stretch: Option<(Hit<F, P>, Hit<F, P>)> = None;
+    for [start, end] in overlaps {
+        let (start, end) = (Hit::new(a, b, start), Hit::new(a, b, end));
+        stretch = match stretch {
+            // The overlaps are sorted by where they start, so one continues the stretch if it starts
+            // where the stretch ends, or somewhere inside of it.
+            Some((first, last)) if start.near(&last, merge_dist) ||
+                    (start.ta <= last.ta && (start.tb - first.tb) * (start.tb - last.tb) <= F::zero()) => {
+                Some(if end.ta > last.ta {(first, end)} else {(first, last)})
+            }
+            Some((first, last)) => {
+                points.push((first.ta, first.tb));
+                points.push((last.ta, last.tb));
+                Some((start, end))
+            }
+            None => Some((start, end))
+        };
+    }
+    if let Some((first, last)) = stretch {
+        points.push((first.ta, first.tb));
+        points.push((last.ta, last.tb));
+    }
+
+    // Neighbouring pieces around a crossing tend to all report it, spread out further along the curves
+    // the shallower the crossing is. As the hits are sorted, each one only needs to be checked against
+    // the one before it, which also chains together all the hits of a spread out crossing.
+    points.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    let mut merged = Vec::with_capacity(points.len());
+    let mut previous: Option<Hit<F, P>> = None;
+    for point in points {
+        let hit = Hit::new(a, b, point);
+        let duplicate = match previous {
+            Some(ref previous) => hit.near(previous, merge_dist),
+            None => false
+        };
+        if !duplicate {
+            merged.push(point);
+        }
+        previous = Some(hit);
+    }
+    merged
+}
+
+/// Like `intersect`, but also returns how many pairs of pieces were checked for intersections.
+pub(crate) fn intersect_counted<F, P, A, B>(a: &A, b: &B, tolerance: F) -> (Vec<(F, F)>, usize)
+        where F: Float,
+              P: Point<F>,
+              A: BezCurve<F, Point = P>,
+              B: BezCurve<F, Point = P> {
+    let a_piece = Piece {points: AsRef::<[P]>::as_ref(a).to_vec(), t0: F::zero(), t1: F::one()};
+    let b_piece = Piece {points: AsRef::<[P]>::as_ref(b).to_vec(), t0: F::zero(), t1: F::one()};
+
+    let mut hits = Hits {points: Vec::new(), overlaps: Vec::new()};
+    let mut steps = 0;
+    find_intersections(a_piece, b_piece, tolerance, 0, &mut hits, &mut steps);
+    (merge_hits(a, b, hits, tolerance), steps)
+}
+
+/// Find the points where the curves `a` and `b` meet, returning the `t` of each point on `a` and on
+/// `b`, sorted by the `t` on `a`.
+///
+/// The curves are repeatedly subdivided, discarding pairs of pieces whose bounding boxes don't overlap,
+/// until the boxes of both pieces are smaller than `tolerance`. Hits within a few times `tolerance` of
+/// each other along both curves are treated as the same intersection. Curves that overlap along a
+/// stretch, rather than crossing, report hits at both ends of the stretch.
+pub fn intersect<F, P, A, B>(a: &A, b: &B, tolerance: F) -> Vec<(F, F)>
+        where F: Float,
+              P: Point<F>,
+              A: BezCurve<F, Point = P>,
+              B: BezCurve<F, Point = P> {
+    intersect_counted(a, b, tolerance).0
+}
+
+/// Like `intersect_clip`, but also returns how many pairs of pieces were checked for intersections.
+pub(crate) fn intersect_clip_counted<F, A, B>(a: &A, b: &B, tolerance: F) -> (Vec<(F, F)>, usize)
+        where F: Float,
+              A: BezCurve<F, Point = Point2d<F>>,
+              B: BezCurve<F, Point = Point2d<F>> {
+    let a_piece = Piece {points: AsRef::<[Point2d<F>]>::as_ref(a).to_vec(), t0: F::zero(), t1: F::one()};
+    let b_piece = Piece {points: AsRef::<[Point2d<F>]>::as_ref(b).to_vec(), t0: F::zero(), t1: F::one()};
+
+    let mut hits = Hits {points: Vec::new(), overlaps: Vec::new()};
+    let mut steps = 0;
+    clip_intersections(a_piece, b_piece, tolerance, 0, false, &mut hits, &mut steps);
+    (merge_hits(a, b, hits, tolerance), steps)
+}
+
+/// Find the points where the 2-dimensional curves `a` and `b` meet, in the same way as `intersect`
+/// but with bezier clipping, which converges much faster.
+///
+/// Rather than always halving the pieces, each piece gets clipped to the range that could be within
+/// the fat line around the other piece, and the roles are then swapped. Where clipping doesn't narrow
+/// a piece down by much, as happens when there are multiple intersections, the larger piece gets split
+/// in half instead.
+pub fn intersect_clip<F, A, B>(a: &A, b: &B, tolerance: F) -> Vec<(F, F)>
+        where F: Float,
+              A: BezCurve<F, Point = Point2d<F>>,
+              B: BezCurve<F, Point = Point2d<F>> {
+    intersect_clip_counted(a, b, tolerance).0
+}
@@ -1,7 +1,23 @@
 //! A crate that provides generic bezier curves of varying order and dimensionality, including numerous
 //! stack-allocated curves and an (admittedly not entirely functional) n-order curve.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(any(feature = "std", test)), macro_use)]
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate core;
 extern crate num_traits;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("either the `std` or the `libm` feature must be enabled for the float math");
 
 #[macro_use]
 mod macros;
@@ -9,18 +25,323 @@ mod macros;
 mod markers;
 pub use markers::*;
 
+#[cfg(feature = "alloc")]
 mod nbez;
+#[cfg(feature = "alloc")]
 pub use nbez::*;
 
-use std::fmt::{Debug, Formatter};
-use std::marker::PhantomData;
+#[cfg(feature = "alloc")]
+mod arc;
+
+#[cfg(feature = "alloc")]
+mod area;
+
+#[cfg(feature = "alloc")]
+mod builder;
+#[cfg(feature = "alloc")]
+pub use builder::*;
+
+#[cfg(feature = "alloc")]
+mod close;
+
+#[cfg(feature = "alloc")]
+mod fit;
+#[cfg(feature = "alloc")]
+pub use fit::*;
+
+#[cfg(feature = "alloc")]
+mod hull;
+
+#[cfg(feature = "alloc")]
+mod intersect;
+#[cfg(feature = "alloc")]
+pub use intersect::*;
+
+#[cfg(feature = "alloc")]
+mod offset;
+
+mod pointn;
+pub use pointn::*;
+
+#[cfg(feature = "alloc")]
+mod quadratic;
+
+#[cfg(feature = "alloc")]
+mod rational;
+#[cfg(feature = "alloc")]
+pub use rational::*;
+
+#[cfg(feature = "alloc")]
+mod roots;
+
+#[cfg(feature = "alloc")]
+mod spline;
+
+#[cfg(feature = "alloc")]
+mod stroke;
+#[cfg(feature = "alloc")]
+pub use stroke::*;
+
+#[cfg(feature = "alloc")]
+mod svg;
 
+mod transform;
+pub use transform::*;
+
+#[cfg(feature = "alloc")]
+mod walk;
+#[cfg(feature = "alloc")]
+pub use walk::*;
+
+#[cfg(feature = "alloc")]
+mod winding;
+#[cfg(feature = "alloc")]
+pub use winding::*;
+
+#[cfg(feature = "std")]
+use std::error::Error;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[cfg(feature = "alloc")]
+use num_traits::identities::Zero;
+
+/// Linearly interpolate between the points or vectors `a` and `b`, getting `a` when `factor` is `0.0`
+/// and `b` when it's `1.0`. This is the same interpolation the curves themselves are built on.
+///
+/// `factor` isn't clamped, so values outside of `0.0` to `1.0` extrapolate along the line through `a`
+/// and `b`.
 #[inline] 
-fn lerp<PV: PVOps<F>, F: Float>(a: PV, b: PV, factor: F) -> PV { 
+pub fn lerp<PV: PVOps<F>, F: Float>(a: PV, b: PV, factor: F) -> PV { 
     let fact1 = F::from_f32(1.0).unwrap() - factor; 
     a * fact1 + b * factor 
 }
 
+/// Linearly interpolate between the scalars `a` and `b`, in the same way as `lerp`. Like `lerp`,
+/// `factor` isn't clamped.
+#[inline]
+pub fn lerp_scalar<F: Float>(a: F, b: F, factor: F) -> F {
+    a * (F::one() - factor) + b * factor
+}
+
+/// Evaluate the polynomial `coeffs[0] + coeffs[1] * t + coeffs[2] * t^2 + ...` at `t` with Horner's
+/// method. Pass this the coefficients from a curve's `to_power_basis` to interpolate the curve.
+pub fn eval_power_basis<PV: PVOps<F> + Copy, F: Float>(coeffs: &[PV], t: F) -> PV {
+    let mut coeffs = coeffs.iter().rev();
+    match coeffs.next() {
+        Some(&highest) => coeffs.fold(highest, |acc, &c| acc * t + c),
+        None => PV::zero()
+    }
+}
+
+/// Get the dot product of two sets of components.
+#[inline]
+fn dot<F: Float>(a: &[F], b: &[F]) -> F {
+    a.iter().zip(b.iter()).fold(F::zero(), |acc, (a, b)| acc + *a * *b)
+}
+
+/// Get the distance from `p` to the line passing through `a` and `b`. If `a` and `b` are the same
+/// point, this is the distance from `p` to `a`.
+fn line_distance<F: Float, P: Point<F>>(p: P, a: P, b: P) -> F {
+    let dir = b - a;
+    let offset = p - a;
+    let dir_len_sq = dot(dir.as_slice(), dir.as_slice());
+
+    let perp = if dir_len_sq == F::zero() {
+        offset
+    } else {
+        offset - dir * (dot(offset.as_slice(), dir.as_slice()) / dir_len_sq)
+    };
+    dot(perp.as_slice(), perp.as_slice()).sqrt()
+}
+
+/// Recursively subdivide the curve described by `points` until its control points are within
+/// `tolerance` of its chord, pushing the end point of each flat piece onto `out`.
+#[cfg(feature = "alloc")]
+fn flatten_points<F: Float, P: Point<F>>(points: &[P], tolerance: F, depth: u32, out: &mut Vec<P>) {
+    let start = points[0];
+    let end = points[points.len() - 1];
+    let flat = points[1..points.len() - 1].iter().all(|p| line_distance(*p, start, end) < tolerance);
+
+    if flat || depth >= 16 {
+        out.push(end);
+    } else {
+        let (left, right) = decasteljau_split(points, F::from_f32(0.5).unwrap());
+        flatten_points(&left, tolerance, depth + 1, out);
+        flatten_points(&right, tolerance, depth + 1, out);
+    }
+}
+
+/// Get the distance between two points.
+fn point_distance<F: Float, P: Point<F>>(a: P, b: P) -> F {
+    let diff = b - a;
+    dot(diff.as_slice(), diff.as_slice()).sqrt()
+}
+
+/// Recursively subdivide `curve` between `t0` and `t1` until splitting a piece in half changes its
+/// estimated length by less than `tolerance`, pushing the end `t` of each piece and the curve's total
+/// length up to that point onto `table`.
+#[cfg(feature = "alloc")]
+fn arc_length_table<F, B>(curve: &B, t0: F, t1: F, tolerance: F, depth: u32, table: &mut Vec<(F, F)>)
+        where F: Float,
+              B: BezCurve<F> {
+    let tm = (t0 + t1) * F::from_f32(0.5).unwrap();
+    let (p0, pm, p1) = (curve.interp_unbounded(t0), curve.interp_unbounded(tm), curve.interp_unbounded(t1));
+    let chord = point_distance(p0, p1);
+    let halves = point_distance(p0, pm) + point_distance(pm, p1);
+
+    // A few subdivisions are always made, so that pieces which happen to have their midpoint on their
+    // chord don't end the search early.
+    if depth >= 3 && (halves - chord <= tolerance || depth >= 24) {
+        let start = table.last().map(|e| e.1).unwrap_or_else(F::zero);
+        table.push((t1, start + halves));
+    } else {
+        arc_length_table(curve, t0, tm, tolerance, depth + 1, table);
+        arc_length_table(curve, tm, t1, tolerance, depth + 1, table);
+    }
+}
+
+/// Find the `t` at which `curve`'s length reaches `target`, within the piece of an arc length table
+/// that starts at the `(t, length)` entry `start` and ends at `end_t`.
+#[cfg(feature = "alloc")]
+fn arc_length_param<F, B>(curve: &B, start: (F, F), end_t: F, target: F) -> F
+        where F: Float,
+              B: BezCurve<F> {
+    // The pieces in the table are close enough to straight that the distance from the start of a piece
+    // approximates the length along it, so bisect the piece to find the point.
+    let half = F::from_f32(0.5).unwrap();
+    let (mut lo, start_len) = start;
+    let mut hi = end_t;
+    if target <= start_len {
+        return lo;
+    }
+
+    let start_point = curve.interp_unbounded(lo);
+    for _ in 0..32 {
+        let mid = (lo + hi) * half;
+        if start_len + point_distance(start_point, curve.interp_unbounded(mid)) < target {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    (lo + hi) * half
+}
+
+/// Get the length of `curve` between `t0` and `t1` by integrating its speed with five-point
+/// Gauss-Legendre quadrature. This is only accurate for pieces short enough that their speed is close
+/// to a low-order polynomial, like the pieces of an arc length table.
+#[cfg(feature = "alloc")]
+fn gauss_length<F, B>(curve: &B, t0: F, t1: F) -> F
+        where F: Float,
+              B: BezCurve<F> {
+    const NODES: [(f64, f64); 5] = [
+        (0.0, 0.568_888_888_888_888_9),
+        (-0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+        (0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+        (-0.906_179_845_938_664, 0.236_926_885_056_189_1),
+        (0.906_179_845_938_664, 0.236_926_885_056_189_1)
+    ];
+
+    let half_width = (t1 - t0) * F::from_f32(0.5).unwrap();
+    let center = t0 + half_width;
+    NODES.iter().fold(F::zero(), |acc, &(node, weight)| {
+        let slope = curve.slope_unbounded(center + half_width * F::from_f64(node).unwrap());
+        acc + dot(slope.as_slice(), slope.as_slice()).sqrt() * F::from_f64(weight).unwrap()
+    }) * half_width
+}
+
+/// Integrate `f` between `a` and `b` with adaptive Simpson's rule, splitting the interval until the
+/// estimate of each piece changes by less than `tolerance`. `fa`, `fm` and `fb` are `f` at `a`, the
+/// midpoint, and `b`, and `whole` is the Simpson estimate over the entire interval.
+#[cfg(feature = "alloc")]
+fn adaptive_simpson<F, G>(f: &G, (a, b): (F, F), (fa, fm, fb): (F, F, F), whole: F, tolerance: F, depth: u32) -> F
+        where F: Float,
+              G: Fn(F) -> F {
+    let half = F::from_f32(0.5).unwrap();
+    let sixth = F::one() / F::from_u32(6).unwrap();
+    let m = (a + b) * half;
+    let (lm, rm) = ((a + m) * half, (m + b) * half);
+    let (flm, frm) = (f(lm), f(rm));
+    let left = (m - a) * sixth * (fa + F::from_u32(4).unwrap() * flm + fm);
+    let right = (b - m) * sixth * (fm + F::from_u32(4).unwrap() * frm + fb);
+
+    // A few subdivisions are always made, so that integrands which happen to line up with the first
+    // few samples don't end the search early.
+    let diff = left + right - whole;
+    if depth >= 3 && (diff.abs() <= F::from_u32(15).unwrap() * tolerance || depth >= 24) {
+        left + right + diff / F::from_u32(15).unwrap()
+    } else {
+        adaptive_simpson(f, (a, m), (fa, flm, fm), left, tolerance * half, depth + 1) +
+        adaptive_simpson(f, (m, b), (fm, frm, fb), right, tolerance * half, depth + 1)
+    }
+}
+
+/// Get the values of `t` strictly between `0.0` and `1.0` where the derivative of the `axis` coordinate
+/// of the curve described by `points` is zero.
+#[cfg(feature = "alloc")]
+fn axis_extrema<F: Float, P: Point<F>>(points: &[P], axis: usize) -> Vec<F> {
+    let merge_dist = F::epsilon().sqrt();
+
+    // The coordinate's derivative, up to a constant factor that doesn't affect its roots.
+    let dcoeffs: Vec<F> = points.windows(2)
+        .map(|w| w[1].as_slice()[axis] - w[0].as_slice()[axis])
+        .collect();
+
+    // Roots at the endpoints are left out, as they're already the ends of the curve.
+    roots::bernstein_roots(&dcoeffs).into_iter()
+        .filter(|t| merge_dist < *t && *t < F::one() - merge_dist)
+        .collect()
+}
+
+/// Evaluate the curve described by `points` at `t` with the de Casteljau algorithm.
+#[cfg(feature = "alloc")]
+fn decasteljau_eval<PV: PVOps<F> + Copy, F: Float>(points: &[PV], t: F) -> PV {
+    let mut pass = points.to_vec();
+
+    for len in (1..pass.len()).rev() {
+        for i in 0..len {
+            pass[i] = lerp(pass[i], pass[i + 1], t);
+        }
+    }
+    pass[0]
+}
+
+/// Split the curve described by `points` at `t` with the de Casteljau algorithm, returning the
+/// control points of the left and right halves. Each reduction pass contributes its first point to
+/// the left half and its last point to the right half.
+#[cfg(feature = "alloc")]
+fn decasteljau_split<PV: PVOps<F> + Copy, F: Float>(points: &[PV], t: F) -> (Vec<PV>, Vec<PV>) {
+    let mut left = Vec::with_capacity(points.len());
+    let mut right = Vec::with_capacity(points.len());
+    let mut pass = points.to_vec();
+
+    while let Some(&last) = pass.last() {
+        left.push(pass[0]);
+        right.push(last);
+
+        for i in 0..pass.len() - 1 {
+            pass[i] = lerp(pass[i], pass[i + 1], t);
+        }
+        pass.pop();
+    }
+
+    // The right half's points were collected from the end of the curve inwards.
+    right.reverse();
+    (left, right)
+}
+
+/// Get the binomial coefficient `n` choose `k`.
+fn binomial<F: Float>(n: usize, k: usize) -> F {
+    (0..k.min(n - k)).fold(F::one(), |acc, i| {
+        acc * F::from_usize(n - i).unwrap() / F::from_usize(i + 1).unwrap()
+    })
+}
+
 // There are macros in place to make it easier to create new bezier structs, as they can be created
 // with a very consistent pattern. However, those macros are also written in a very consistent pattern
 // which unfortunately is significantly harder, if not impossible, to create with a traditional
@@ -35,6 +356,149 @@ impl<F: Float> Vector2d<F> {
             y: self.x
         }
     }
+
+    /// Returns the z component of the cross product of `self` and `rhs`, treating both as 3-dimensional
+    /// vectors lying in the xy plane. This is positive if `rhs` is counterclockwise from `self`.
+    pub fn cross(self, rhs: Vector2d<F>) -> F {
+        self.x * rhs.y - self.y * rhs.x
+    }
+
+    /// Returns `self` reflected across the line perpendicular to `normal`, as if bouncing off of a
+    /// surface with that normal. `normal` is assumed to have a length of one.
+    pub fn reflect(self, normal: Vector2d<F>) -> Vector2d<F> {
+        let two = F::from_f32(2.0).unwrap();
+        self - normal * (two * self.dot(normal))
+    }
+
+    /// Returns `self` rotated counterclockwise by `radians`.
+    pub fn rotate(self, radians: F) -> Vector2d<F> {
+        let (sin, cos) = radians.sin_cos();
+        Vector2d {
+            x: self.x * cos - self.y * sin,
+            y: self.x * sin + self.y * cos
+        }
+    }
+
+    /// Returns the counterclockwise angle in radians from the positive x axis to `self`, on `-π` to `π`.
+    pub fn angle(self) -> F {
+        self.y.atan2(self.x)
+    }
+
+    /// Returns the signed angle in radians that `self` needs to be rotated by to point in the same
+    /// direction as `other`, on `-π` to `π`. This is positive if `other` is counterclockwise from `self`.
+    pub fn angle_between(self, other: Vector2d<F>) -> F {
+        self.cross(other).atan2(self.dot(other))
+    }
+}
+
+impl<F: Float> Vector3d<F> {
+    /// Returns the cross product of `self` and `rhs`, which is perpendicular to both.
+    pub fn cross(self, rhs: Vector3d<F>) -> Vector3d<F> {
+        Vector3d {
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x
+        }
+    }
+}
+
+impl<F: Float, P: Point<F>> Bez3o<F, P> {
+    /// Get the quadratic curve of this curve's derivative, with the control points `3(ctrl0 - start)`,
+    /// `3(ctrl1 - ctrl0)`, and `3(end - ctrl1)`. Interpolating it at `t` gives this curve's slope at
+    /// `t`.
+    pub fn hodograph(&self) -> Bez2o<F, P> {
+        let three = F::from_u32(3).unwrap();
+        Bez2o::new(
+            (self.ctrl0 - self.start) * three,
+            (self.ctrl1 - self.ctrl0) * three,
+            (self.end - self.ctrl1) * three
+        )
+    }
+}
+
+impl<F: Float, P: Point<F>> Bez2o<F, P> {
+    /// Get the cubic curve that traces exactly the same path as this curve, with the control points
+    /// `start`, `start + 2/3(ctrl - start)`, `end + 2/3(ctrl - end)`, and `end`.
+    pub fn to_cubic(&self) -> Bez3o<F, P> {
+        let two_thirds = F::from_u32(2).unwrap() / F::from_u32(3).unwrap();
+        Bez3o::new(
+            self.start,
+            self.start + (self.ctrl - self.start) * two_thirds,
+            self.end + (self.ctrl - self.end) * two_thirds,
+            self.end
+        )
+    }
+}
+
+impl<F: Float> Bez3o<F, Point2d<F>> {
+    /// Get the sorted values of `t` on `0.0` to `1.0` inclusive where the curve's curvature changes
+    /// sign. Points where the curvature touches zero without changing sign aren't included.
+    #[cfg(feature = "alloc")]
+    pub fn inflections(&self) -> Vec<F> {
+        let a = Vector2d::from(self.ctrl0 - self.start);
+        let b = Vector2d::from(self.ctrl1 - self.ctrl0) - a;
+        let c = Vector2d::from(self.end - self.ctrl1) - a - b * F::from_f32(2.0).unwrap();
+
+        // With the derivative written as `3(a + 2bt + ct²)` and the second derivative as `6(b + ct)`,
+        // the cubic terms of their cross product cancel out, leaving this quadratic.
+        let (q2, q1, q0) = (b.cross(c), a.cross(c), a.cross(b));
+
+        let mut roots = Vec::with_capacity(2);
+        if q2 == F::zero() {
+            if q1 != F::zero() {
+                roots.push(-q0 / q1);
+            }
+        } else {
+            let discriminant = q1 * q1 - F::from_f32(4.0).unwrap() * q2 * q0;
+            if discriminant > F::zero() {
+                let sqrt = discriminant.sqrt();
+                let denom = F::from_f32(2.0).unwrap() * q2;
+                roots.push((-q1 - sqrt) / denom);
+                roots.push((-q1 + sqrt) / denom);
+            }
+        }
+
+        roots.retain(|t| F::zero() <= *t && *t <= F::one());
+        roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        roots
+    }
+
+    /// Get the two distinct values of `t` on `0.0` to `1.0` inclusive where the curve crosses itself,
+    /// with the smaller one first. Returns `None` if the curve doesn't contain a loop.
+    pub fn self_intersection(&self) -> Option<(F, F)> {
+        let three = F::from_f32(3.0).unwrap();
+        let a = Vector2d::from(self.ctrl0 - self.start);
+        let b = Vector2d::from(self.ctrl1 - self.ctrl0);
+        let c = Vector2d::from(self.end - self.ctrl1);
+
+        // The curve is `start + c1 t + c2 t² + c3 t³`. Points `s` and `t` coincide when
+        // `(B(s) - B(t)) / (s - t) = c1 + c2 (s + t) + c3 ((s + t)² - st)` is zero, which gives the sum
+        // and product of `s` and `t`.
+        let c1 = a * three;
+        let c2 = (b - a) * three;
+        let c3 = c - b - b + a;
+
+        let denom = c3.cross(c2);
+        if denom == F::zero() || c3.dot(c3) == F::zero() {
+            return None;
+        }
+        let sum = c1.cross(c3) / denom;
+        let product = sum * sum + (c1 + c2 * sum).dot(c3) / c3.dot(c3);
+
+        let discriminant = sum * sum - F::from_f32(4.0).unwrap() * product;
+        if discriminant <= F::zero() {
+            return None;
+        }
+        let half = F::from_f32(0.5).unwrap();
+        let s = (sum - discriminant.sqrt()) * half;
+        let t = (sum + discriminant.sqrt()) * half;
+
+        if F::zero() <= s && t <= F::one() {
+            Some((s, t))
+        } else {
+            None
+        }
+    }
 }
 
 /// Iterator over bezier curve chains
@@ -51,7 +515,7 @@ impl<'a, F, B> Iterator for BezIter<'a, F, B>
               B: BezCurve<F> + OrderStatic {
     type Item = B;
     fn next(&mut self) -> Option<B> {
-        use std::slice;
+        use core::slice;
 
         let order = B::order_static();
 
@@ -66,8 +530,7 @@ impl<'a, F, B> Iterator for BezIter<'a, F, B>
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let order = B::order_static();
-        let size = (self.len - (self.len - 1) % order) / order;
+        let size = if self.len == 0 {0} else {(self.len - 1) / B::order_static()};
         (size, Some(size))
     }
 }
@@ -76,16 +539,16 @@ impl<'a, F, B> DoubleEndedIterator for BezIter<'a, F, B>
         where F: Float,
               B: BezCurve<F> + OrderStatic {
     fn next_back(&mut self) -> Option<B> {
-        use std::slice;
+        use core::slice;
 
         let order = B::order_static();
 
-        // If there are any control points in the iterator that can't be used to create a full curve,
-        // ignore them.
-        let end = self.len - (self.len - 1) % order;
-        if end <= order {            
+        if self.len <= order {
             None
         } else {unsafe{
+            // If there are any control points in the iterator that can't be used to create a full
+            // curve, ignore them.
+            let end = self.len - (self.len - 1) % order;
             let slice = slice::from_raw_parts(self.points.offset((end-order-1) as isize), order + 1);
             self.len -= order;
             B::from_slice(slice)
@@ -113,19 +576,49 @@ pub struct InterpIter<'a, F, B>
     samples: F
 }
 
-impl<'a, F, B> Iterator for InterpIter<'a, F, B>
+impl<'a, F, B> InterpIter<'a, F, B>
         where F: Float,
               B: BezCurve<F> {
-    type Item = B::Point;
-    fn next(&mut self) -> Option<B::Point> {
+    /// Get the `t` of the next point from the front of the iterator, advancing past it.
+    fn next_t(&mut self) -> Option<F> {
         if self.t_nodiv <= self.t_back_nodiv {
-            let point = self.curve.interp(F::from_u32(self.t_nodiv).unwrap() / self.samples);
+            let t = F::from_u32(self.t_nodiv).unwrap() / self.samples;
             self.t_nodiv += 1;
-            point
+            Some(t)
+        } else {
+            None
+        }
+    }
+
+    /// Get the `t` of the next point from the back of the iterator, advancing past it.
+    fn next_back_t(&mut self) -> Option<F> {
+        if self.t_nodiv <= self.t_back_nodiv {
+            let t = F::from_u32(self.t_back_nodiv).unwrap() / self.samples;
+
+            // Because `t_back_nodiv` is unsigned, we can't let it go below zero. So, this checks if
+            // `t_back_nodiv` is zero, and if it is set `t_nodiv` to 1, which causes any future calls
+            // to the iterator to properly return `None`
+            if 0 == self.t_back_nodiv {
+                self.t_nodiv = 1;
+            } else {
+                self.t_back_nodiv -= 1;
+            }
+
+            Some(t)
         } else {
             None
         }
     }
+}
+
+impl<'a, F, B> Iterator for InterpIter<'a, F, B>
+        where F: Float,
+              B: BezCurve<F> {
+    type Item = B::Point;
+    fn next(&mut self) -> Option<B::Point> {
+        let curve = self.curve;
+        self.next_t().map(|t| curve.interp_unbounded(t))
+    }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
         let size = if self.t_back_nodiv < self.t_nodiv {
@@ -142,34 +635,60 @@ impl<'a, F, B> DoubleEndedIterator for InterpIter<'a, F, B>
         where F: Float,
               B: BezCurve<F> {
     fn next_back(&mut self) -> Option<B::Point> {
-        if self.t_nodiv <= self.t_back_nodiv {
-            let point = self.curve.interp(F::from_u32(self.t_back_nodiv).unwrap() / self.samples);
+        let curve = self.curve;
+        self.next_back_t().map(|t| curve.interp_unbounded(t))
+    }
+}
 
-            // Because `t_back_nodiv` is unsigned, we can't let it go below zero. So, this checks if
-            // `t_back_nodiv` is zero, and if it is set `t_nodiv` to 1, which causes any future calls
-            // to the iterator to properly return `None`
-            if 0 == self.t_back_nodiv {
-                self.t_nodiv = 1;
-            } else {
-                self.t_back_nodiv -= 1;
-            }
+impl<'a, F, B> ExactSizeIterator for InterpIter<'a, F, B>
+        where F: Float,
+              B: BezCurve<F> {}
 
-            point
-        } else {
-            None
-        }
+/// An iterator over the parameters, interpolated points, and slopes of evenly spaced samples of a
+/// bezier curve. Created by `BezCurve::frames`.
+pub struct FrameIter<'a, F, B>
+        where F: Float,
+              B: 'a + BezCurve<F> {
+    samples: InterpIter<'a, F, B>
+}
+
+impl<'a, F, B> Iterator for FrameIter<'a, F, B>
+        where F: Float,
+              B: BezCurve<F> {
+    type Item = (F, B::Point, <B::Point as Point<F>>::Vector);
+    fn next(&mut self) -> Option<Self::Item> {
+        let curve = self.samples.curve;
+        self.samples.next_t().map(|t| (t, curve.interp_unbounded(t), curve.slope_unbounded(t)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.samples.size_hint()
     }
 }
 
-impl<'a, F, B> ExactSizeIterator for InterpIter<'a, F, B>
+impl<'a, F, B> DoubleEndedIterator for FrameIter<'a, F, B>
+        where F: Float,
+              B: BezCurve<F> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let curve = self.samples.curve;
+        self.samples.next_back_t().map(|t| (t, curve.interp_unbounded(t), curve.slope_unbounded(t)))
+    }
+}
+
+impl<'a, F, B> ExactSizeIterator for FrameIter<'a, F, B>
         where F: Float,
               B: BezCurve<F> {}
 
+/// A point on a curve along with the curve's first and second derivatives there, as returned by
+/// `BezCurve::eval_all`.
+#[cfg(feature = "alloc")]
+type PointDerivatives<F, P> = (P, <P as Point<F>>::Vector, <P as Point<F>>::Vector);
+
 /// Bezier curve trait
 pub trait BezCurve<F: Float>: AsRef<[<Self as BezCurve<F>>::Point]> + AsMut<[<Self as BezCurve<F>>::Point]>
         where Self: Sized {
     type Point: Point<F>;
-    type Elevated: BezCurve<F, Point = Self::Point>;
+    type Split: BezCurve<F, Point = Self::Point>;
 
     /// Attempt to create a curve from a slice. Fails if the slice's length does not match the
     /// curve's order + 1.
@@ -181,6 +700,10 @@ pub trait BezCurve<F: Float>: AsRef<[<Self as BezCurve<F>>::Point]> + AsMut<[<Se
         check_t_bounds!(t);
         Some(self.interp_unbounded(t))
     }
+    /// Like `interp`, but returns `BezError::ParameterOutOfRange` if `t` is not within bounds.
+    fn try_interp(&self, t: F) -> Result<Self::Point, BezError> {
+        self.interp(t).ok_or_else(|| BezError::ParameterOutOfRange(t.to_f64().unwrap()))
+    }
     /// Perform interpolation on the curve with no range bounds
     fn interp_unbounded(&self, t: F) -> Self::Point;
 
@@ -190,93 +713,1013 @@ pub trait BezCurve<F: Float>: AsRef<[<Self as BezCurve<F>>::Point]> + AsMut<[<Se
         check_t_bounds!(t);
         Some(self.slope_unbounded(t))
     }
+    /// Like `slope`, but returns `BezError::ParameterOutOfRange` if `t` is not within bounds.
+    fn try_slope(&self, t: F) -> Result<<Self::Point as Point<F>>::Vector, BezError> {
+        self.slope(t).ok_or_else(|| BezError::ParameterOutOfRange(t.to_f64().unwrap()))
+    }
     /// Get the slope for the given `t` with no range bounds
     fn slope_unbounded(&self, t: F) -> <Self::Point as Point<F>>::Vector;
 
-    /// Elevate the curve order, getting a curve that is one order higher but gives the same results
-    /// upon interpolation
-    fn elevate(&self) -> Self::Elevated;
+    /// Get the second derivative for the given `t`, bounded on `0.0` to `1.0` inclusive. Returns `None`
+    /// if `t` is not within bounds.
+    #[cfg(feature = "alloc")]
+    fn slope2(&self, t: F) -> Option<<Self::Point as Point<F>>::Vector> {
+        check_t_bounds!(t);
+        Some(self.slope2_unbounded(t))
+    }
+    /// Get the second derivative for the given `t` with no range bounds
+    #[cfg(feature = "alloc")]
+    fn slope2_unbounded(&self, t: F) -> <Self::Point as Point<F>>::Vector {
+        self.nth_derivative_unbounded(2, t)
+    }
+
+    /// Get the `n`th derivative for the given `t`, bounded on `0.0` to `1.0` inclusive. Returns `None`
+    /// if `t` is not within bounds.
+    #[cfg(feature = "alloc")]
+    fn nth_derivative(&self, n: usize, t: F) -> Option<<Self::Point as Point<F>>::Vector> {
+        check_t_bounds!(t);
+        Some(self.nth_derivative_unbounded(n, t))
+    }
+    /// Get the `n`th derivative for the given `t` with no range bounds. The zeroth derivative is the
+    /// interpolated point, and any derivative higher than the curve's order is zero.
+    #[cfg(feature = "alloc")]
+    fn nth_derivative_unbounded(&self, n: usize, t: F) -> <Self::Point as Point<F>>::Vector {
+        let mut diffs = AsRef::<[Self::Point]>::as_ref(self).to_vec();
+        let order = diffs.len() - 1;
+        if n > order {
+            return Self::Point::zero().into();
+        }
+
+        // Each differentiation replaces the points with the differences between adjacent points and
+        // multiplies the curve by its current order.
+        let mut weight = F::one();
+        for i in 0..n {
+            for j in 0..diffs.len() - 1 {
+                diffs[j] = diffs[j + 1] - diffs[j];
+            }
+            diffs.pop();
+            weight = weight * F::from_usize(order - i).unwrap();
+        }
+        (decasteljau_eval(&diffs, t) * weight).into()
+    }
+
+    /// Get the interpolated point, slope, and second derivative for the given `t`, bounded on `0.0`
+    /// to `1.0` inclusive. Returns `None` if `t` is not within bounds.
+    #[cfg(feature = "alloc")]
+    fn eval_all(&self, t: F) -> Option<PointDerivatives<F, Self::Point>> {
+        check_t_bounds!(t);
+        Some(self.eval_all_unbounded(t))
+    }
+    /// Get the interpolated point, slope, and second derivative for the given `t` with no range
+    /// bounds. This is a single de Casteljau pass over the control points, which is cheaper than
+    /// calling `interp_unbounded`, `slope_unbounded`, and `slope2_unbounded` separately.
+    #[cfg(feature = "alloc")]
+    fn eval_all_unbounded(&self, t: F) -> PointDerivatives<F, Self::Point> {
+        let mut pass = AsRef::<[Self::Point]>::as_ref(self).to_vec();
+        let order = pass.len() - 1;
+        let t1 = F::one() - t;
+        let (mut slope, mut slope2) = (Self::Point::zero(), Self::Point::zero());
+
+        // The derivatives only depend on the last few points of the pass, where the differences
+        // between them give the derivatives' values at `t`.
+        for len in (1..pass.len()).rev() {
+            if len == 2 {
+                let weight = F::from_usize(order * (order - 1)).unwrap();
+                slope2 = (pass[2] - pass[1] - pass[1] + pass[0]) * weight;
+            } else if len == 1 {
+                slope = (pass[1] - pass[0]) * F::from_usize(order).unwrap();
+            }
+            for i in 0..len {
+                pass[i] = pass[i] * t1 + pass[i + 1] * t;
+            }
+        }
+        (pass[0], slope.into(), slope2.into())
+    }
+
+    /// Get a copy of this curve with its control points in reverse order, which traces the same path in
+    /// the opposite direction. Interpolating the reversed curve at `t` is equivalent to interpolating
+    /// this curve at `1 - t`.
+    fn reversed(&self) -> Self
+            where Self: Clone {
+        let mut curve = self.clone();
+        AsMut::<[Self::Point]>::as_mut(&mut curve).reverse();
+        curve
+    }
 
     /// Split the curve at the given `t`, bounded on `0.0` to `1.0` inclusive. Returns `None` if `t` is
     /// not within bounds.
-    fn split(&self, t: F) -> Option<(Self, Self)> {
+    fn split(&self, t: F) -> Option<(Self::Split, Self::Split)> {
         check_t_bounds!(t);
         Some(self.split_unbounded(t))
     }
 
     /// Split the curve with no range bounds
-    fn split_unbounded(&self, t: F) -> (Self, Self);
-    
-    /// Gets the order of the curve
-    fn order(&self) -> usize;
+    fn split_unbounded(&self, t: F) -> (Self::Split, Self::Split);
 
-    /// Get an iterator over the interpolated values of this curve, splitting the curve into the given
-    /// number of samples.
-    fn interp_iter<'a>(&'a self, samples: u32) -> InterpIter<'a, F, Self> {
-        InterpIter {
-            curve: self,
-            t_nodiv: 0,
-            t_back_nodiv: samples,
-            samples: F::from_u32(samples).unwrap()
-        }
+    /// Split the curve in half at `t = 0.5`. This is the same as `split(0.5)`, but as the midpoint is
+    /// always within bounds, it doesn't need to return an `Option`.
+    fn bisect(&self) -> (Self::Split, Self::Split) {
+        self.split_unbounded(F::from_f32(0.5).unwrap())
     }
-}
 
-/// Trait to mark curves that have order known at compiletime.
-pub trait OrderStatic {
-    /// Gets the compiletime-known curve order.
-    fn order_static() -> usize;
-}
+    /// Get the part of the curve between `t0` and `t1`, both bounded on `0.0` to `1.0` inclusive, as a
+    /// curve of its own. Returns `None` if either parameter is not within bounds.
+    #[cfg(feature = "alloc")]
+    fn subsegment(&self, t0: F, t1: F) -> Option<NBez<F, Self::Point, Vec<Self::Point>>> {
+        check_t_bounds!(t0);
+        check_t_bounds!(t1);
+        Some(self.subsegment_unbounded(t0, t1))
+    }
 
+    /// Get the part of the curve between `t0` and `t1` with no range bounds. The returned curve goes
+    /// from `t0` at its start to `t1` at its end, so it's reversed if `t0` is greater than `t1`.
+    #[cfg(feature = "alloc")]
+    fn subsegment_unbounded(&self, t0: F, t1: F) -> NBez<F, Self::Point, Vec<Self::Point>> {
+        let (lo, hi) = if t0 <= t1 {(t0, t1)} else {(t1, t0)};
 
-/// A chain of bezier curves, with the last point of each curve being the first point of the next.
-#[derive(Clone, Copy)]
-pub struct BezChain<F, B, C>
-        where F: Float,
-              B: BezCurve<F> + OrderStatic,
-              C: AsRef<[B::Point]> {
-    points: C,
-    phantom: PhantomData<(F, B)>
-}
+        let (_, rest) = decasteljau_split(AsRef::<[Self::Point]>::as_ref(self), lo);
+        // The rest of the curve covers `lo` to `1.0`, so `hi` has to be rescaled onto it. If `lo` is
+        // `1.0`, the rest is a single point and any `t` gives the same result.
+        let rest_t = if lo == F::one() {F::zero()} else {(hi - lo) / (F::one() - lo)};
+        let (mut points, _) = decasteljau_split(&rest, rest_t);
 
-impl<F, B, C> BezChain<F, B, C>
-        where F: Float,
-              B: BezCurve<F> + OrderStatic,
-              C: AsRef<[B::Point]> {
-    /// Create a new BezChain by wrapping around a container.
-    #[inline]
-    pub fn from_container(container: C) -> BezChain<F, B, C> {
-        BezChain {
-            points: container,
-            phantom: PhantomData
+        if t0 > t1 {
+            points.reverse();
         }
+        NBez::from_container(points)
     }
 
-    /// Get the bezier curve that is `index` curves away from the start. Returns `None` if not enough
-    /// points exist for the given curve index.
-    pub fn get(&self, index: usize) -> Option<B> {
-        let len = self.points.as_ref().len();
-        let order = B::order_static();
-        let curve_index = index * order;
-        let curve_end_index = index * order + order + 1;
+    /// Split the curve at each of the given `t`s, getting the curves between them in order. Values of
+    /// `t` that aren't strictly between `0.0` and `1.0` are ignored, as are duplicates, so splitting at
+    /// `n` distinct values gives `n + 1` curves.
+    #[cfg(feature = "alloc")]
+    fn split_many(&self, ts: &[F]) -> Vec<NBez<F, Self::Point, Vec<Self::Point>>> {
+        let mut ts: Vec<F> = ts.iter().cloned().filter(|&t| F::zero() < t && t < F::one()).collect();
+        ts.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        ts.dedup();
+
+        let mut curves = Vec::with_capacity(ts.len() + 1);
+        let mut rest = AsRef::<[Self::Point]>::as_ref(self).to_vec();
+        let mut rest_start = F::zero();
+        for t in ts {
+            // The rest of the curve covers `rest_start` to `1.0`, so `t` has to be rescaled onto it.
+            let (left, right) = decasteljau_split(&rest, (t - rest_start) / (F::one() - rest_start));
+            curves.push(NBez::from_container(left));
+            rest = right;
+            rest_start = t;
+        }
+        curves.push(NBez::from_container(rest));
+        curves
+    }
+    
+    /// Gets the order of the curve
+    fn order(&self) -> usize;
 
-        if curve_end_index > len - (len - 1) % order {
-            None
+    /// Determine whether the curve is a point, a line, or neither. The curve is a point if every
+    /// control point is within `tolerance` of the start, and a line if every control point is within
+    /// `tolerance` of the line through the start and the control point furthest from it.
+    fn classify(&self, tolerance: F) -> CurveKind {
+        let points = AsRef::<[Self::Point]>::as_ref(self);
+        let start = points[0];
+        let (far, far_dist) = points.iter().fold((start, F::zero()), |(far, far_dist), &p| {
+            let dist = point_distance(start, p);
+            if dist > far_dist {(p, dist)} else {(far, far_dist)}
+        });
+
+        if far_dist <= tolerance {
+            CurveKind::Point
+        } else if points.iter().all(|&p| line_distance(p, start, far) <= tolerance) {
+            CurveKind::Line
         } else {
-            Some(B::from_slice(&self.points.as_ref()[curve_index..curve_end_index]).unwrap())
+            CurveKind::Curved
         }
     }
 
-    /// Get an iterator over all curves in the chain.
-    #[inline]
-    pub fn iter(&self) -> BezIter<F, B> {
-        BezIter {
-            points: self.points.as_ref().as_ptr(),
+    /// Get the sorted values of `t` on `0.0` to `1.0` inclusive where the derivative of any of the
+    /// curve's coordinates is zero, along with the endpoints. Between any two adjacent values, every
+    /// coordinate of the curve is monotonic.
+    #[cfg(feature = "alloc")]
+    fn extrema(&self) -> Vec<F> {
+        let points = AsRef::<[Self::Point]>::as_ref(self);
+        let dims = points[0].as_slice().len();
+        let merge_dist = F::epsilon().sqrt();
+
+        let mut extrema = vec![F::zero(), F::one()];
+        for axis in 0..dims {
+            extrema.extend(axis_extrema(points, axis));
+        }
+
+        extrema.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        extrema.dedup_by(|a, b| *a - *b <= merge_dist);
+        extrema
+    }
+
+    /// Get the sorted values of `t` on `0.0` to `1.0` inclusive where the coordinate on `axis` equals
+    /// `value`, such as where the curve crosses a horizontal or vertical line. If the curve lies
+    /// entirely on the line, it has no isolated crossings and none are returned.
+    ///
+    /// Panics if `axis` is not less than the number of dimensions of the curve's points.
+    #[cfg(feature = "alloc")]
+    fn solve_axis(&self, axis: usize, value: F) -> Vec<F> {
+        let coeffs: Vec<F> = AsRef::<[Self::Point]>::as_ref(self).iter()
+            .map(|p| p.as_slice()[axis] - value)
+            .collect();
+        roots::bernstein_roots(&coeffs)
+    }
+
+    /// Get the axis-aligned bounding box of the curve, returned as its minimum and maximum corners.
+    ///
+    /// The box is found by evaluating the curve at its extrema, so it fits the curve exactly rather
+    /// than the control points.
+    #[cfg(feature = "alloc")]
+    fn bounding_box(&self) -> (Self::Point, Self::Point) {
+        let start = AsRef::<[Self::Point]>::as_ref(self)[0];
+
+        self.extrema().into_iter().fold((start, start), |(min, max), t| {
+            let p = self.interp_unbounded(t);
+            (min.min(p), max.max(p))
+        })
+    }
+
+    /// Find the point on the curve closest to `p`, returning its `t` and the point itself. `tolerance`
+    /// is how close in `t` successive refinement steps must get before the search stops.
+    ///
+    /// The curve is first sampled coarsely to find the closest region, which is then refined with
+    /// Newton's method. Refinement never leaves `0.0` to `1.0`, and falls back to bisection whenever a
+    /// Newton step would leave the region that's known to contain the closest point.
+    #[cfg(feature = "alloc")]
+    fn project(&self, p: Self::Point, tolerance: F) -> (F, Self::Point) {
+        let dist_sq = |t: F| {
+            let d = self.interp_unbounded(t) - p;
+            dot(d.as_slice(), d.as_slice())
+        };
+        // The derivative of the squared distance, divided by two. Zero at the closest point.
+        let ddist = |t: F| {
+            let d = self.interp_unbounded(t) - p;
+            dot(d.as_slice(), self.slope_unbounded(t).as_slice())
+        };
+
+        let samples = 16 + 4 * self.order();
+        let samples_f = F::from_usize(samples).unwrap();
+        let mut best = 0;
+        let mut best_dist = dist_sq(F::zero());
+        for i in 1..samples + 1 {
+            let dist = dist_sq(F::from_usize(i).unwrap() / samples_f);
+            if dist < best_dist {
+                best = i;
+                best_dist = dist;
+            }
+        }
+
+        let zero = F::zero();
+        let half = F::from_f32(0.5).unwrap();
+        let mut t = F::from_usize(best).unwrap() / samples_f;
+        let mut lo = F::from_usize(best.saturating_sub(1)).unwrap() / samples_f;
+        let mut hi = F::from_usize((best + 1).min(samples)).unwrap() / samples_f;
+
+        for _ in 0..64 {
+            let f = ddist(t);
+            if f == zero {
+                break;
+            } else if f < zero {
+                lo = t;
+            } else {
+                hi = t;
+            }
+
+            let d = self.interp_unbounded(t) - p;
+            let slope = self.slope_unbounded(t);
+            let df = dot(slope.as_slice(), slope.as_slice()) + dot(d.as_slice(), self.slope2_unbounded(t).as_slice());
+
+            let mut next = t - f / df;
+            if !(df > zero && lo <= next && next <= hi) {
+                next = (lo + hi) * half;
+            }
+
+            let step = (next - t).abs();
+            t = next;
+            if step < tolerance {
+                break;
+            }
+        }
+
+        // The closest point may be an endpoint that the refinement converged away from.
+        let mut closest = (t, dist_sq(t));
+        for &end in &[zero, F::one()] {
+            let dist = dist_sq(end);
+            if dist < closest.1 {
+                closest = (end, dist);
+            }
+        }
+        (closest.0, self.interp_unbounded(closest.0))
+    }
+
+    /// Approximate the curve with a polyline, returning the polyline's points in order from the start
+    /// of the curve to the end. The curve is subdivided until no control point of any piece is further
+    /// than `tolerance` from the line between that piece's endpoints.
+    #[cfg(feature = "alloc")]
+    fn flatten(&self, tolerance: F) -> Vec<Self::Point> {
+        let points = AsRef::<[Self::Point]>::as_ref(self);
+        let mut polyline = vec![points[0]];
+        flatten_points(points, tolerance, 0, &mut polyline);
+        polyline
+    }
+
+    /// Get the distance from the start of the curve to its end, which no curve with those ends is
+    /// shorter than.
+    fn chord_length(&self) -> F {
+        let points = AsRef::<[Self::Point]>::as_ref(self);
+        point_distance(points[0], points[points.len() - 1])
+    }
+
+    /// Get the total length of the lines between consecutive control points. As the curve is always
+    /// shorter than its control polygon, this and `chord_length` bracket the length of the curve.
+    fn control_polygon_length(&self) -> F {
+        AsRef::<[Self::Point]>::as_ref(self).windows(2)
+            .fold(F::zero(), |acc, w| acc + point_distance(w[0], w[1]))
+    }
+
+    /// Get the length of the curve, approximated by a polyline that's within `tolerance` of the
+    /// curve's length.
+    #[cfg(feature = "alloc")]
+    fn arc_length(&self, tolerance: F) -> F {
+        let mut table = Vec::new();
+        arc_length_table(self, F::zero(), F::one(), tolerance, 0, &mut table);
+        table.last().unwrap().1
+    }
+
+    /// Get `count` points on the curve that are evenly spaced along the curve's length, from its start
+    /// to its end inclusive. `tolerance` is how accurately the curve's length gets measured.
+    ///
+    /// This builds a table of the curve's length at increasing values of `t`, and finds the `t` of each
+    /// point by searching between the table's entries.
+    #[cfg(feature = "alloc")]
+    fn samples_by_arclength(&self, count: usize, tolerance: F) -> Vec<Self::Point> {
+        if count < 2 {
+            return self.samples(count).collect();
+        }
+
+        let mut table = vec![(F::zero(), F::zero())];
+        arc_length_table(self, F::zero(), F::one(), tolerance, 0, &mut table);
+        let total = table[table.len() - 1].1;
+
+        let mut points = Vec::with_capacity(count);
+        points.push(self.interp_unbounded(F::zero()));
+        let mut entry = 1;
+        for i in 1..count - 1 {
+            let target = total * F::from_usize(i).unwrap() / F::from_usize(count - 1).unwrap();
+            while entry < table.len() - 1 && table[entry].1 < target {
+                entry += 1;
+            }
+
+            let t = arc_length_param(self, table[entry - 1], table[entry].0, target);
+            points.push(self.interp_unbounded(t));
+        }
+        points.push(self.interp_unbounded(F::one()));
+        points
+    }
+
+    /// Get the `t` at which the curve reaches the fraction `s` of its total length, where `s` is
+    /// clamped to `0.0` to `1.0`. Stepping `s` by equal amounts moves the same distance along the curve
+    /// each step, which makes an object animated along the curve move at a constant speed. `tolerance`
+    /// is how accurately the curve's length gets measured.
+    ///
+    /// The `t` is first estimated from a table of the curve's length, like `samples_by_arclength`
+    /// does, and then refined with Newton's method.
+    #[cfg(feature = "alloc")]
+    fn constant_speed_param(&self, s: F, tolerance: F) -> F {
+        let s = s.max(F::zero()).min(F::one());
+        if s == F::zero() || s == F::one() {
+            return s;
+        }
+
+        let mut table = vec![(F::zero(), F::zero())];
+        arc_length_table(self, F::zero(), F::one(), tolerance, 0, &mut table);
+        let target = table[table.len() - 1].1 * s;
+
+        let mut entry = 1;
+        while entry < table.len() - 1 && table[entry].1 < target {
+            entry += 1;
+        }
+        let (start_t, start_len) = table[entry - 1];
+        let end_t = table[entry].0;
+
+        let mut t = arc_length_param(self, table[entry - 1], end_t, target);
+        for _ in 0..8 {
+            let slope = self.slope_unbounded(t);
+            let speed = dot(slope.as_slice(), slope.as_slice()).sqrt();
+            if speed == F::zero() {
+                break;
+            }
+
+            let step = (start_len + gauss_length(self, start_t, t) - target) / speed;
+            t = (t - step).max(start_t).min(end_t);
+            if step.abs() <= F::epsilon() {
+                break;
+            }
+        }
+        t
+    }
+
+    /// Get an iterator over the interpolated values of this curve, splitting the curve into the given
+    /// number of samples.
+    fn interp_iter<'a>(&'a self, samples: u32) -> InterpIter<'a, F, Self> {
+        InterpIter {
+            curve: self,
+            t_nodiv: 0,
+            t_back_nodiv: samples,
+            // With zero samples, the only point is the start of the curve.
+            samples: F::from_u32(samples.max(1)).unwrap()
+        }
+    }
+
+    /// Get an iterator over `count` points on the curve, evenly spaced in `t` from `0.0` to `1.0`
+    /// inclusive. A `count` of one gives only the start of the curve.
+    fn samples<'a>(&'a self, count: usize) -> InterpIter<'a, F, Self> {
+        let mut iter = self.interp_iter(count.saturating_sub(1) as u32);
+        if count == 0 {
+            iter.t_nodiv = 1;
+        }
+        iter
+    }
+
+    /// Get an iterator over `count` evenly spaced samples of the curve, in the same places as
+    /// `samples`. Each sample is the sample's `t`, the point on the curve at `t`, and the curve's
+    /// slope at `t`, which isn't normalized.
+    fn frames<'a>(&'a self, count: usize) -> FrameIter<'a, F, Self> {
+        FrameIter {
+            samples: self.samples(count)
+        }
+    }
+
+    /// Get the curve's speed, which is the length of its slope, at `count` evenly spaced values of `t`
+    /// in the same places as `samples`. Plotting this shows where the curve speeds up and slows down.
+    #[cfg(feature = "alloc")]
+    fn speed_profile(&self, count: usize) -> Vec<F> {
+        let mut samples = self.samples(count);
+        let mut speeds = Vec::with_capacity(count);
+        while let Some(t) = samples.next_t() {
+            speeds.push(self.speed_unbounded(t));
+        }
+        speeds
+    }
+
+    /// Get the speed of the curve, which is the length of its slope, for the given `t`, bounded on
+    /// `0.0` to `1.0` inclusive. Returns `None` if `t` is not within bounds.
+    fn speed(&self, t: F) -> Option<F> {
+        check_t_bounds!(t);
+        Some(self.speed_unbounded(t))
+    }
+
+    /// Get the speed of the curve for the given `t` with no range bounds.
+    fn speed_unbounded(&self, t: F) -> F {
+        let slope = self.slope_unbounded(t);
+        dot(slope.as_slice(), slope.as_slice()).sqrt()
+    }
+
+    /// Get the sorted values of `t` on `0.0` to `1.0` inclusive where the curve's speed drops to
+    /// `tolerance` or less, which are the curve's cusps. The curve's direction isn't defined at a
+    /// cusp, so it can change suddenly there.
+    ///
+    /// Only the places where the speed is at a minimum are checked, and those are found exactly with
+    /// the roots of the derivative of the squared speed.
+    #[cfg(feature = "alloc")]
+    fn cusps(&self, tolerance: F) -> Vec<F> {
+        let points = AsRef::<[Self::Point]>::as_ref(self);
+        let dims = points[0].as_slice().len();
+
+        // The squared speed's derivative, halved, is the dot product of the first two derivatives.
+        let mut ddot: Vec<F> = Vec::new();
+        for axis in 0..dims {
+            let coeffs: Vec<F> = points.iter().map(|p| p.as_slice()[axis]).collect();
+            let d1 = area::derivative(&coeffs);
+            let d2 = area::derivative(&d1);
+            let product = area::mul(&d1, &d2);
+            if ddot.is_empty() {
+                ddot = product;
+            } else {
+                for (acc, c) in ddot.iter_mut().zip(product) {
+                    *acc = *acc + c;
+                }
+            }
+        }
+
+        let mut cusps: Vec<F> = roots::bernstein_roots(&ddot);
+        cusps.push(F::zero());
+        cusps.push(F::one());
+        cusps.retain(|&t| self.speed_unbounded(t) <= tolerance);
+        cusps.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let merge_dist = F::epsilon().sqrt();
+        cusps.dedup_by(|a, b| *a - *b <= merge_dist);
+        cusps
+    }
+
+    /// Get the same points as `samples`, interpolating them in parallel.
+    #[cfg(feature = "rayon")]
+    fn par_samples(&self, count: usize) -> Vec<Self::Point>
+            where Self: Sync,
+                  Self::Point: Send,
+                  F: Send + Sync {
+        use rayon::prelude::*;
+
+        // This has to divide in the same way as `InterpIter` to get exactly the same points.
+        let samples = F::from_u32((count.saturating_sub(1) as u32).max(1)).unwrap();
+        (0..count).into_par_iter()
+            .map(|i| self.interp_unbounded(F::from_u32(i as u32).unwrap() / samples))
+            .collect()
+    }
+}
+
+/// Order elevation of bezier curves. The highest-order stack-allocated curve elevates to an `NBez`,
+/// so this needs the `alloc` feature.
+#[cfg(feature = "alloc")]
+pub trait BezElevate<F: Float>: BezCurve<F> {
+    type Elevated: BezCurve<F, Point = Self::Point>;
+
+    /// Elevate the curve order, getting a curve that is one order higher but gives the same results
+    /// upon interpolation
+    fn elevate(&self) -> Self::Elevated;
+}
+
+/// The methods of 2-dimensional bezier curves, which are implemented for every `BezCurve` with
+/// `Point2d` points.
+pub trait BezCurve2d<F: Float>: BezCurve<F, Point = Point2d<F>> {
+    /// Get the unit tangent of a 2-dimensional curve for the given `t`, bounded on `0.0` to `1.0`
+    /// inclusive. Returns `None` if `t` is not within bounds.
+    fn tangent(&self, t: F) -> Option<Vector2d<F>> {
+        check_t_bounds!(t);
+        Some(self.tangent_unbounded(t))
+    }
+    /// Get the unit tangent of a 2-dimensional curve for the given `t` with no range bounds. This is
+    /// the normalized slope of the curve. Returns a zero vector where the curve's speed is zero (at a
+    /// cusp), where the tangent is undefined.
+    fn tangent_unbounded(&self, t: F) -> Vector2d<F> {
+        let slope = self.slope_unbounded(t);
+        if slope.len() == F::zero() {
+            slope
+        } else {
+            slope.normalize()
+        }
+    }
+
+    /// Get the unit normal of a 2-dimensional curve for the given `t`, bounded on `0.0` to `1.0`
+    /// inclusive. Returns `None` if `t` is not within bounds.
+    fn normal(&self, t: F) -> Option<Vector2d<F>> {
+        check_t_bounds!(t);
+        Some(self.normal_unbounded(t))
+    }
+    /// Get the unit normal of a 2-dimensional curve for the given `t` with no range bounds. This is the
+    /// tangent rotated 90 degrees counter-clockwise, pointing to the left of the direction of travel.
+    /// Like the tangent, this is a zero vector at a cusp.
+    fn normal_unbounded(&self, t: F) -> Vector2d<F> {
+        self.tangent_unbounded(t).perp()
+    }
+
+    /// Get the signed curvature of a 2-dimensional curve for the given `t`, bounded on `0.0` to `1.0`
+    /// inclusive. Returns `None` if `t` is not within bounds.
+    #[cfg(feature = "alloc")]
+    fn curvature(&self, t: F) -> Option<F> {
+        check_t_bounds!(t);
+        Some(self.curvature_unbounded(t))
+    }
+    /// Get the signed curvature of a 2-dimensional curve for the given `t` with no range bounds.
+    /// Positive curvature means the curve is turning counter-clockwise. Returns zero where the curve's
+    /// speed is zero (at a cusp), where curvature is undefined.
+    #[cfg(feature = "alloc")]
+    fn curvature_unbounded(&self, t: F) -> F {
+        let d1 = self.slope_unbounded(t);
+        let d2 = self.slope2_unbounded(t);
+
+        let speed_sq = d1.x * d1.x + d1.y * d1.y;
+        if speed_sq == F::zero() {
+            F::zero()
+        } else {
+            (d1.x * d2.y - d1.y * d2.x) / speed_sq.powf(F::from_f32(1.5).unwrap())
+        }
+    }
+
+    /// Get the total angle in radians that this 2-dimensional curve's tangent turns through, counting
+    /// turns in either direction as positive. This is the integral of the absolute curvature over the
+    /// curve's length, integrated to within `tolerance`, so a straight line gives zero and a full
+    /// circle gives `2π`.
+    #[cfg(feature = "alloc")]
+    fn total_curvature(&self, tolerance: F) -> F {
+        // The absolute curvature times the speed, which is the rate the tangent turns at in `t`.
+        let turning = |t: F| {
+            let d1 = self.slope_unbounded(t);
+            let d2 = self.slope2_unbounded(t);
+            let speed_sq = d1.x * d1.x + d1.y * d1.y;
+            if speed_sq == F::zero() {
+                F::zero()
+            } else {
+                (d1.x * d2.y - d1.y * d2.x).abs() / speed_sq
+            }
+        };
+
+        let half = F::from_f32(0.5).unwrap();
+        let (fa, fm, fb) = (turning(F::zero()), turning(half), turning(F::one()));
+        let whole = (fa + F::from_u32(4).unwrap() * fm + fb) / F::from_u32(6).unwrap();
+        adaptive_simpson(&turning, (F::zero(), F::one()), (fa, fm, fb), whole, tolerance, 0)
+    }
+
+    /// Approximate the curve that's `distance` away from this 2-dimensional curve with a sequence of
+    /// cubic curves, each of which is within `tolerance` of the true offset. Positive distances offset
+    /// the curve to the left of its direction of travel, and negative distances offset it to the right.
+    #[cfg(feature = "alloc")]
+    fn offset(&self, distance: F, tolerance: F) -> Vec<NBez<F, Point2d<F>, Vec<Point2d<F>>>> {
+        offset::offset(self, distance, tolerance)
+    }
+
+    /// Get the closed outline of a stroke along this 2-dimensional curve that's `width` wide, with its
+    /// ends shaped by `cap`. The sides of the stroke are the curve's offsets by half of `width` in
+    /// either direction, approximated to within `tolerance` in the same way as `offset`.
+    #[cfg(feature = "alloc")]
+    fn stroke(&self, width: F, cap: CapStyle, tolerance: F) -> BezChain<F, Bez3o<F, Point2d<F>>, Vec<Point2d<F>>> {
+        stroke::stroke_curve(self, width, cap, tolerance)
+    }
+
+    /// Get a copy of this 2-dimensional curve with `m` applied to it. As affine transformations of a
+    /// curve's control points are affine transformations of the curve itself, this transforms each
+    /// control point.
+    fn transformed(&self, m: &AffineMatrix2d<F>) -> Self
+            where Self: Clone {
+        let mut curve = self.clone();
+        for point in AsMut::<[Point2d<F>]>::as_mut(&mut curve) {
+            *point = m.transform_point(*point);
+        }
+        curve
+    }
+
+    /// Get a copy of this 2-dimensional curve reflected across the line which passes through `through`
+    /// in the direction of `axis`. Each control point gets reflected, so the point at `t` on the
+    /// mirrored curve is the reflection of the point at `t` on this curve, but the mirrored curve turns
+    /// the opposite way, reversing the sign of values like `signed_area`.
+    fn mirror(&self, through: Point2d<F>, axis: Vector2d<F>) -> Self
+            where Self: Clone {
+        let normal = axis.perp().normalize();
+        let mut curve = self.clone();
+        for point in AsMut::<[Point2d<F>]>::as_mut(&mut curve) {
+            *point = through + Vector2d::from(*point - through).reflect(normal);
+        }
+        curve
+    }
+
+    /// Get the signed area enclosed by this 2-dimensional curve, which is positive if the curve goes
+    /// around the area counter-clockwise and negative if it goes clockwise. If the end of the curve
+    /// isn't its start, the curve is closed with a straight line between them.
+    ///
+    /// This is calculated exactly from the control points with Green's theorem.
+    #[cfg(feature = "alloc")]
+    fn signed_area(&self) -> F {
+        let points = AsRef::<[Point2d<F>]>::as_ref(self);
+        area::area_integrals(points).0 + area::area_integrals(&[points[points.len() - 1], points[0]]).0
+    }
+
+    /// Get the vertices of the convex hull of this 2-dimensional curve's control points, in counter-
+    /// clockwise order. As the curve lies entirely within this hull, it's a cheap conservative bound
+    /// on the curve's shape. If all of the control points are collinear, only the two extreme points
+    /// are returned.
+    #[cfg(feature = "alloc")]
+    fn control_hull(&self) -> Vec<Point2d<F>> {
+        hull::convex_hull(AsRef::<[Point2d<F>]>::as_ref(self))
+    }
+
+    /// Get the fat line of this 2-dimensional curve, which is the narrowest band parallel to the line
+    /// through the curve's ends that contains all of its control points, and so all of the curve. This
+    /// is returned as the unit direction from the start of the curve to its end, along with the
+    /// smallest and largest signed distances of the control points from the line through the ends,
+    /// with distances to the left of the direction being positive. If the curve ends where it starts,
+    /// there's no line, so the direction and both distances are zero.
+    #[cfg(feature = "alloc")]
+    fn fat_line(&self) -> (Vector2d<F>, F, F) {
+        intersect::fat_line(AsRef::<[Point2d<F>]>::as_ref(self))
+    }
+
+    /// Get an oriented bounding box of this 2-dimensional curve, as its center and two vectors going
+    /// from the center to the middle of two adjacent sides. The first vector lies along the line from
+    /// the start of the curve to its end, which makes the box much tighter than `bounding_box` for
+    /// long diagonal curves. If the curve ends where it starts, the box is instead oriented towards
+    /// the control point furthest from the start.
+    #[cfg(feature = "alloc")]
+    fn obb(&self) -> (Point2d<F>, Vector2d<F>, Vector2d<F>) {
+        let points = AsRef::<[Point2d<F>]>::as_ref(self);
+        let start = points[0];
+
+        let mut axis = Vector2d::from(points[points.len() - 1] - start);
+        if axis.len() == F::zero() {
+            axis = points.iter()
+                .map(|p| Vector2d::from(*p - start))
+                .fold(axis, |far, d| if d.len() > far.len() {d} else {far});
+        }
+        let u = if axis.len() == F::zero() {Vector2d::new(F::one(), F::zero())} else {axis.normalize()};
+        let v = u.perp();
+
+        // Find the bounds of the curve in the box's coordinate system.
+        let local: NBez<F, Point2d<F>> = NBez::from_container(points.iter().map(|p| {
+            let d = Vector2d::from(*p - start);
+            Point2d::new(d.dot(u), d.dot(v))
+        }).collect());
+        let (min, max) = local.bounding_box();
+
+        let half = F::from_f32(0.5).unwrap();
+        let center = start + u * ((min.x + max.x) * half) + v * ((min.y + max.y) * half);
+        (center, u * ((max.x - min.x) * half), v * ((max.y - min.y) * half))
+    }
+
+    /// Get the sorted values of `t` on `0.0` to `1.0` inclusive where this 2-dimensional curve crosses
+    /// or touches the infinite line through `origin` going in the direction `dir`. If the curve lies
+    /// entirely on the line, it has no isolated crossings and none are returned.
+    #[cfg(feature = "alloc")]
+    fn intersect_line(&self, origin: Point2d<F>, dir: Vector2d<F>) -> Vec<F> {
+        // The distance of each control point from the line, scaled by the length of `dir`.
+        let coeffs: Vec<F> = AsRef::<[Point2d<F>]>::as_ref(self).iter()
+            .map(|p| dir.cross(Vector2d::from(*p - origin)))
+            .collect();
+        roots::bernstein_roots(&coeffs)
+    }
+
+    /// Like `intersect_line`, but only gets the crossings with the ray that starts at `origin` and goes
+    /// in the direction `dir`.
+    #[cfg(feature = "alloc")]
+    fn intersect_ray(&self, origin: Point2d<F>, dir: Vector2d<F>) -> Vec<F> {
+        let mut ts = self.intersect_line(origin, dir);
+        ts.retain(|&t| Vector2d::from(self.interp_unbounded(t) - origin).dot(dir) >= F::zero());
+        ts
+    }
+
+    /// Like `intersect_line`, but only gets the crossings with the line segment from `start` to `end`.
+    #[cfg(feature = "alloc")]
+    fn intersect_segment(&self, start: Point2d<F>, end: Point2d<F>) -> Vec<F> {
+        let dir = Vector2d::from(end - start);
+        let len_sq = dir.dot(dir);
+        let mut ts = self.intersect_line(start, dir);
+        ts.retain(|&t| {
+            let s = Vector2d::from(self.interp_unbounded(t) - start).dot(dir);
+            F::zero() <= s && s <= len_sq
+        });
+        ts
+    }
+
+    /// Split this 2-dimensional curve into pieces that are each monotonic in x, by splitting it
+    /// wherever the slope's x component is zero.
+    #[cfg(feature = "alloc")]
+    fn split_monotone_x(&self) -> Vec<NBez<F, Point2d<F>, Vec<Point2d<F>>>> {
+        self.split_many(&axis_extrema(AsRef::<[Point2d<F>]>::as_ref(self), 0))
+    }
+
+    /// Split this 2-dimensional curve into pieces that are each monotonic in y, by splitting it
+    /// wherever the slope's y component is zero.
+    #[cfg(feature = "alloc")]
+    fn split_monotone_y(&self) -> Vec<NBez<F, Point2d<F>, Vec<Point2d<F>>>> {
+        self.split_many(&axis_extrema(AsRef::<[Point2d<F>]>::as_ref(self), 1))
+    }
+}
+
+impl<F, B> BezCurve2d<F> for B
+        where F: Float,
+              B: BezCurve<F, Point = Point2d<F>> {}
+
+/// Trait to mark curves that have order known at compiletime.
+pub trait OrderStatic {
+    /// Gets the compiletime-known curve order.
+    fn order_static() -> usize;
+}
+
+/// Returns true if the curves `a` and `b` are approximately the same, by interpolating both at `samples`
+/// evenly spaced values of `t` from `0.0` to `1.0` inclusive and checking that every component of each
+/// pair of points is within `epsilon`. Stops at the first pair of points that differ.
+///
+/// As only the interpolated points are compared, curves with different orders or control points can
+/// still be approximately equal, such as a curve and its elevation.
+pub fn curve_approx_eq<F, A, B>(a: &A, b: &B, samples: usize, epsilon: F) -> bool
+        where F: Float,
+              A: BezCurve<F>,
+              B: BezCurve<F, Point = A::Point> {
+    a.samples(samples).zip(b.samples(samples)).all(|(pa, pb)| {
+        pa.as_slice().iter().zip(pb.as_slice()).all(|(ca, cb)| (*ca - *cb).abs() <= epsilon)
+    })
+}
+
+/// Find the curve in `curves` that comes closest to `p`, returning its index, the `t` of its closest
+/// point as found by `BezCurve::project`, and that point's distance from `p`. `tolerance` is passed on
+/// to `project`. Returns `None` if `curves` is empty.
+///
+/// As each curve lies within the box around its control points, curves whose boxes are further from
+/// `p` than the closest curve found so far are skipped without being projected onto.
+#[cfg(feature = "alloc")]
+pub fn nearest_curve<F, B>(curves: &[B], p: B::Point, tolerance: F) -> Option<(usize, F, F)>
+        where F: Float,
+              B: BezCurve<F> {
+    // The distance from `p` to each curve's control point box, which no point on the curve is closer
+    // than.
+    let mut bounds: Vec<(usize, F)> = curves.iter().enumerate().map(|(i, curve)| {
+        let points = AsRef::<[B::Point]>::as_ref(curve);
+        let (min, max) = points[1..].iter().fold((points[0], points[0]), |(min, max), &point| {
+            (min.min(point), max.max(point))
+        });
+        let dist_sq = p.as_slice().iter().zip(min.as_slice().iter().zip(max.as_slice())).fold(F::zero(), |acc, (&c, (&lo, &hi))| {
+            let outside = (lo - c).max(c - hi).max(F::zero());
+            acc + outside * outside
+        });
+        (i, dist_sq.sqrt())
+    }).collect();
+    bounds.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+
+    let mut nearest: Option<(usize, F, F)> = None;
+    for (i, bound) in bounds {
+        if nearest.is_some_and(|n| bound >= n.2) {
+            break;
+        }
+
+        let (t, point) = curves[i].project(p, tolerance);
+        let dist = point_distance(point, p);
+        if nearest.is_none_or(|n| dist < n.2) {
+            nearest = Some((i, t, dist));
+        }
+    }
+    nearest
+}
+
+/// Get `count` evenly spaced points on each of `curves`, in the same way as `BezCurve::samples`,
+/// sampling the curves in parallel.
+#[cfg(feature = "rayon")]
+pub fn par_sample_many<F, B>(curves: &[B], count: usize) -> Vec<Vec<B::Point>>
+        where F: Float,
+              B: BezCurve<F> + Sync,
+              B::Point: Send {
+    use rayon::prelude::*;
+
+    curves.par_iter().map(|curve| curve.samples(count).collect()).collect()
+}
+
+/// A wrapper around a point or vector that implements `Eq` and `Hash` by comparing the exact bits of
+/// its components, so that points can be used as keys in hash maps and sets.
+///
+/// Positive and negative zero are treated as the same value. All NaNs are treated as the same value
+/// as each other, so a point with a NaN component is equal to itself.
+#[derive(Debug, Clone, Copy)]
+pub struct HashablePoint<P>(pub P);
+
+/// Get a key that uniquely identifies the value of `f`, with positive and negative zero sharing a key
+/// and all NaNs sharing a key.
+fn float_key<F: Float>(f: F) -> (u64, i16, i8) {
+    if f.is_nan() {
+        (0, 0, 0)
+    } else if f == F::zero() {
+        F::zero().integer_decode()
+    } else {
+        f.integer_decode()
+    }
+}
+
+/// The shape of a curve, as determined by `BezCurve::classify`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurveKind {
+    /// Every control point is at the same place, so the curve doesn't go anywhere.
+    Point,
+    /// The control points all lie on one line, so the curve does too.
+    Line,
+    /// The curve actually curves.
+    Curved
+}
+
+/// An error produced while constructing a curve or chain. More kinds of errors may be added, and
+/// `ParseError` is only there with the `alloc` feature, so matches on this need a wildcard arm.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum BezError {
+    /// Text being parsed was malformed. Contains a description of what went wrong.
+    #[cfg(feature = "alloc")]
+    ParseError(String),
+    /// A curve parameter was outside of `0.0` to `1.0`. Contains the parameter.
+    ParameterOutOfRange(f64),
+    /// An index didn't refer to anything in a curve or chain. Contains the index.
+    IndexOutOfRange(usize),
+    /// A curve didn't have the order it needed to have. Contains the needed order, then the curve's
+    /// order.
+    OrderMismatch(usize, usize),
+    /// A curve would have had a higher order than is supported. Contains the order.
+    OrderTooHigh(usize)
+}
+
+impl Display for BezError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            #[cfg(feature = "alloc")]
+            BezError::ParseError(ref desc) => write!(f, "parse error: {}", desc),
+            BezError::ParameterOutOfRange(t) => write!(f, "curve parameter {} is outside of 0 to 1", t),
+            BezError::IndexOutOfRange(index) => write!(f, "index {} is out of range", index),
+            BezError::OrderMismatch(expected, found) => write!(f, "expected a curve of order {}, found order {}", expected, found),
+            BezError::OrderTooHigh(order) => write!(f, "curves of order {} are unsupported", order)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for BezError {}
+
+
+/// A chain of bezier curves, with the last point of each curve being the first point of the next.
+#[derive(Clone, Copy)]
+pub struct BezChain<F, B, C>
+        where F: Float,
+              B: BezCurve<F> + OrderStatic,
+              C: AsRef<[B::Point]> {
+    points: C,
+    phantom: PhantomData<(F, B)>
+}
+
+impl<F, B, C> BezChain<F, B, C>
+        where F: Float,
+              B: BezCurve<F> + OrderStatic,
+              C: AsRef<[B::Point]> {
+    /// Create a new BezChain by wrapping around a container.
+    #[inline]
+    pub fn from_container(container: C) -> BezChain<F, B, C> {
+        BezChain {
+            points: container,
+            phantom: PhantomData
+        }
+    }
+
+    /// Get the bezier curve that is `index` curves away from the start. Returns `None` if not enough
+    /// points exist for the given curve index.
+    pub fn get(&self, index: usize) -> Option<B> {
+        let order = B::order_static();
+        let curve_index = index * order;
+        let curve_end_index = index * order + order + 1;
+
+        if index >= self.segment_count() {
+            None
+        } else {
+            Some(B::from_slice(&self.points.as_ref()[curve_index..curve_end_index]).unwrap())
+        }
+    }
+
+    /// Get the number of full curves in the chain.
+    pub fn segment_count(&self) -> usize {
+        let len = self.points.as_ref().len();
+        if len == 0 {
+            0
+        } else {
+            (len - 1) / B::order_static()
+        }
+    }
+
+    /// Perform interpolation across the entire chain for the given `t`, bounded on `0.0` to `1.0`
+    /// inclusive. Each curve in the chain covers an equal part of `t`'s range. Returns `None` if `t` is
+    /// not within bounds or if the chain doesn't contain any full curves.
+    pub fn interp(&self, t: F) -> Option<B::Point> {
+        check_t_bounds!(t);
+        let count = self.segment_count();
+        if count == 0 {
+            return None;
+        }
+
+        let scaled = t * F::from_usize(count).unwrap();
+        // `t == 1.0` lands on the end of the last curve rather than the start of a nonexistent one.
+        let index = scaled.floor().to_usize().unwrap().min(count - 1);
+        let local_t = scaled - F::from_usize(index).unwrap();
+        self.get(index).map(|curve| curve.interp_unbounded(local_t))
+    }
+
+    /// Get an iterator over all curves in the chain.
+    #[inline]
+    pub fn iter(&self) -> BezIter<F, B> {
+        BezIter {
+            points: self.points.as_ref().as_ptr(),
             len: self.points.as_ref().len(),
             lifetime: PhantomData
         }
     }
 
+    /// Get a chain tracing the same path in the opposite direction, with both the order of the curves
+    /// and the order of each curve's points reversed. Trailing points that don't form a full curve are
+    /// left out.
+    #[cfg(feature = "alloc")]
+    pub fn reversed(&self) -> BezChain<F, B, Vec<B::Point>> {
+        let points = self.points.as_ref();
+        let len = points.len();
+        let end = if len == 0 {0} else {len - (len - 1) % B::order_static()};
+
+        let mut reversed = points[..end].to_vec();
+        reversed.reverse();
+        BezChain::from_container(reversed)
+    }
+
+    /// Returns true if the chain ends within `tolerance` of where it starts. Trailing points that don't
+    /// form a full curve are ignored, and a chain without any curves is never closed.
+    pub fn is_closed(&self, tolerance: F) -> bool {
+        let points = self.points.as_ref();
+        if points.len() <= B::order_static() {
+            return false;
+        }
+
+        let end = points.len() - 1 - (points.len() - 1) % B::order_static();
+        point_distance(points[0], points[end]) <= tolerance
+    }
+
     /// Get the order of the chain's curves. Identical to order_static().
     #[inline]
     pub fn order(&self) -> usize {
@@ -290,6 +1733,38 @@ impl<F, B, C> BezChain<F, B, C>
     }
 }
 
+impl<F, B, C> BezChain<F, B, C>
+        where F: Float,
+              B: BezCurve<F> + OrderStatic,
+              C: AsRef<[B::Point]> + AsMut<[B::Point]> {
+    /// Move the joint `joint_index` to `new_pos`, where joint `i` is the start point of curve `i` and
+    /// the joint after the last curve is its end point. If `maintain_g1` is true, the control points on
+    /// either side of the joint get moved along with it, which preserves the direction of the tangents
+    /// going into and out of the joint. Returns `BezError::IndexOutOfRange` if the joint doesn't exist.
+    pub fn set_joint(&mut self, joint_index: usize, new_pos: B::Point, maintain_g1: bool) -> Result<(), BezError> {
+        let count = self.segment_count();
+        if count == 0 || joint_index > count {
+            return Err(BezError::IndexOutOfRange(joint_index));
+        }
+
+        let order = B::order_static();
+        let points = self.points.as_mut();
+        let index = joint_index * order;
+        let delta = new_pos - points[index];
+        points[index] = new_pos;
+
+        if maintain_g1 {
+            if index > 0 {
+                points[index - 1] = points[index - 1] + delta;
+            }
+            if joint_index < count {
+                points[index + 1] = points[index + 1] + delta;
+            }
+        }
+        Ok(())
+    }
+}
+
 impl<F, B, C> OrderStatic for BezChain<F, B, C>
         where F: Float,
               B: BezCurve<F> + OrderStatic,
@@ -322,7 +1797,7 @@ impl<F, B, C> Debug for BezChain<F, B, C>
         where F: Float,
               B: BezCurve<F> + OrderStatic,
               C: AsRef<[B::Point]> + Debug {
-    fn fmt(&self, f: &mut Formatter) -> Result<(), ::std::fmt::Error> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         f.debug_tuple("BezChain")
             .field(&self.points)
             .finish()
@@ -337,12 +1812,7 @@ mod tests {
     fn test_poly_eq<B1, B2>(nbez_poly: &B1, bez_poly: &B2)
             where B1: BezCurve<f64, Point = f64>,
                   B2: BezCurve<f64, Point = f64> {
-        let mut t = 0.0;
-        while t <= 1.0 {
-            // Assert that values are equal within a tolerance.
-            assert!((nbez_poly.interp(t).unwrap() - bez_poly.interp(t).unwrap()).abs() <= 0.000000001);
-            t += 1.0/30.0;
-        }
+        assert!(curve_approx_eq(nbez_poly, bez_poly, 31, 0.000000001));
     }
 
     fn test_poly_slope_eq<B1, B2>(nbez_poly: &B1, bez_poly: &B2) 
@@ -393,7 +1863,7 @@ mod tests {
     }
 
     fn test_bez_elevation<B>(curve: &B)
-            where B: BezCurve<f64, Point = f64> {
+            where B: BezElevate<f64, Point = f64> {
         
         let elevated = curve.elevate();
         test_poly_eq(curve, &elevated);
@@ -434,9 +1904,58 @@ mod tests {
         test_bez_elevation(&nbez_poly);
     }
 
-    fn test_bez_split<B>(curve: &B)
-            where B: BezCurve<f64, Point = f64> {
-
+    #[test]
+    fn nbez_reduce() {
+        let original = vec![
+            Point2d::new(0.0, 0.0),
+            Point2d::new(1.0, 2.0),
+            Point2d::new(3.0, -1.0),
+            Point2d::new(4.0, 1.0)
+        ];
+        let nbez_poly: NBez<f64> = NBez::from_container(original.clone());
+
+        let (reduced, error) = nbez_poly.elevate().reduce();
+        assert!(error <= 0.000000001);
+        assert_eq!(3, reduced.order());
+        for (a, b) in original.iter().zip((reduced.as_ref() as &[Point2d<f64>]).iter()) {
+            assert!((a.x - b.x).abs() <= 0.000000001 && (a.y - b.y).abs() <= 0.000000001);
+        }
+
+        // A genuine cubic can't be represented exactly by a quadratic.
+        let (reduced, error) = nbez_poly.reduce();
+        assert_eq!(2, reduced.order());
+        assert!(error > 0.1);
+        for i in 0..11 {
+            let t = i as f64 / 10.0;
+            let diff = nbez_poly.interp(t).unwrap() - reduced.interp(t).unwrap();
+            assert!(Vector2d::from(diff).len() <= error);
+        }
+    }
+
+    #[test]
+    fn nbez_threads() {
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+        let nbez_poly: NBez<f64, f64> = NBez::from_container(vec![0.0, 1.0, -1.0, 2.0, -2.0, 3.0]);
+        let bez5o = Bez5o::new(0.0, 1.0, -1.0, 2.0, -2.0, 3.0);
+        assert_send_sync(&nbez_poly);
+
+        let nbez_ref = &nbez_poly;
+        ::std::thread::scope(|scope| {
+            for i in 0..4 {
+                scope.spawn(move || {
+                    for j in 0..30 {
+                        let t = (i * 30 + j) as f64 / 120.0;
+                        assert!((bez5o.interp(t).unwrap() - nbez_ref.interp(t).unwrap()).abs() <= 0.000000001);
+                    }
+                });
+            }
+        });
+    }
+
+    fn test_bez_split<B>(curve: &B)
+            where B: BezCurve<f64, Point = f64> {
+
         // Sanity check bool to make sure the assertions are actually running
         let mut has_run = false;
 
@@ -488,6 +2007,1995 @@ mod tests {
         test_bez_split(&bez6o);
     }
 
+    #[test]
+    fn nbez_split() {
+        let mut nbez_poly = NBez::from_container(Vec::with_capacity(7));
+        (nbez_poly.as_mut() as &mut Vec<f64>).push(0.0);
+        (nbez_poly.as_mut() as &mut Vec<f64>).push(1.0);
+
+        test_bez_split(&nbez_poly);
+
+        (nbez_poly.as_mut() as &mut Vec<f64>).push(-1.0);
+        test_bez_split(&nbez_poly);
+        
+        (nbez_poly.as_mut() as &mut Vec<f64>).push(2.0);
+        test_bez_split(&nbez_poly);
+        
+        (nbez_poly.as_mut() as &mut Vec<f64>).push(-2.0);
+        test_bez_split(&nbez_poly);
+        
+        (nbez_poly.as_mut() as &mut Vec<f64>).push(3.0);
+        test_bez_split(&nbez_poly);
+        
+        (nbez_poly.as_mut() as &mut Vec<f64>).push(-3.0);
+        test_bez_split(&nbez_poly);
+    }
+
+    #[test]
+    fn nbez_split_endpoints() {
+        let nbez_poly: NBez<f64, f64> = NBez::from_container(vec![0.0, 1.0, -1.0, 2.0]);
+
+        let (left, right) = nbez_poly.split(0.0).unwrap();
+        assert_eq!(3, left.order());
+        assert_eq!(3, right.order());
+        assert!((left.as_ref() as &[f64]).iter().all(|p| *p == 0.0));
+        test_poly_eq(&nbez_poly, &right);
+
+        let (left, right) = nbez_poly.split(1.0).unwrap();
+        assert_eq!(3, left.order());
+        assert_eq!(3, right.order());
+        test_poly_eq(&nbez_poly, &left);
+        assert!((right.as_ref() as &[f64]).iter().all(|p| *p == 2.0));
+
+        assert!(nbez_poly.split(1.5).is_none());
+    }
+
+    #[test]
+    fn bounding_box() {
+        let arch: Bez2o<f64> = Bez2o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(2.0, 0.0));
+        let (min, max) = arch.bounding_box();
+        assert!((min.x - 0.0).abs() <= 0.000000001 && (min.y - 0.0).abs() <= 0.000000001);
+        assert!((max.x - 2.0).abs() <= 0.000000001 && (max.y - 1.0).abs() <= 0.000000001);
+
+        // An s-shaped cubic whose x-extrema lie strictly inside the curve.
+        let s_curve: NBez<f64> = NBez::from_container(vec![
+            Point2d::new(0.0, 0.0),
+            Point2d::new(4.0, 1.0),
+            Point2d::new(-3.0, 2.0),
+            Point2d::new(1.0, 3.0)
+        ]);
+        let (min, max) = s_curve.bounding_box();
+        for p in s_curve.interp_iter(1000) {
+            assert!(min.x <= p.x && p.x <= max.x);
+            assert!(min.y <= p.y && p.y <= max.y);
+        }
+        assert!((max.x - 1.2833494471).abs() <= 0.000001);
+        assert!((min.x + 0.2833494471).abs() <= 0.000001);
+        assert!((min.y - 0.0).abs() <= 0.000000001 && (max.y - 3.0).abs() <= 0.000000001);
+
+        // The derivative of a straight line has no roots, so only the endpoints are used.
+        let line = Bez3o::new(
+            Point2d::new(0.0, 0.0),
+            Point2d::new(1.0, 1.0),
+            Point2d::new(2.0, 2.0),
+            Point2d::new(3.0, 3.0)
+        );
+        assert_eq!((Point2d::new(0.0, 0.0), Point2d::new(3.0, 3.0)), line.bounding_box());
+    }
+
+    #[test]
+    fn nbez_decasteljau_precision() {
+        let coeffs: Vec<f64> = (0..16).map(|i| if i % 2 == 0 {1000.0} else {-1000.0} + i as f64).collect();
+        let reference: NBez<f64, f64> = NBez::from_container(coeffs.clone());
+        let nbez_poly: NBez<f32, f32> = NBez::from_container(coeffs.iter().map(|c| *c as f32).collect());
+        assert_eq!(15, nbez_poly.order());
+
+        let mut bernstein_error = 0.0;
+        let mut decasteljau_error = 0.0;
+        for i in 0..101 {
+            let t = i as f64 / 100.0;
+            let expected = reference.interp_decasteljau(t);
+
+            bernstein_error += (nbez_poly.interp_unbounded(t as f32) as f64 - expected).abs();
+            decasteljau_error += (nbez_poly.interp_decasteljau(t as f32) as f64 - expected).abs();
+        }
+
+        assert!(decasteljau_error < bernstein_error);
+    }
+
+    #[test]
+    fn nbez_high_order() {
+        // High-order curves exceed the range where the coefficients' factorials fit in a u64.
+        let coeffs: Vec<f64> = (0..31).map(|i| (i as f64 * 0.7).sin()).collect();
+        let nbez_poly: NBez<f64, f64> = NBez::from_container(coeffs);
+        assert_eq!(30, nbez_poly.order());
+
+        for i in 0..31 {
+            let t = i as f64 / 30.0;
+            assert!((nbez_poly.interp(t).unwrap() - nbez_poly.interp_decasteljau(t)).abs() <= 0.000000001);
+        }
+        assert!((nbez_poly.interp(0.0).unwrap() - 0.0).abs() <= 0.000000001);
+        assert!((nbez_poly.interp(1.0).unwrap() - 21.0f64.sin()).abs() <= 0.000000001);
+        assert!((nbez_poly.slope(0.0).unwrap() - 30.0 * 0.7f64.sin()).abs() <= 0.000000001);
+
+        let max_order: NBez<f64, f64> = NBez::from_container(vec![1.0; 68]);
+        assert!((max_order.interp(0.5).unwrap() - 1.0).abs() <= 0.000000001);
+    }
+
+    #[test]
+    fn try_interp() {
+        let bez3o = Bez3o::new(0.0, 1.0, -1.0, 2.0);
+        assert_eq!(Ok(bez3o.interp(0.5).unwrap()), bez3o.try_interp(0.5));
+        assert_eq!(Err(BezError::ParameterOutOfRange(1.5)), bez3o.try_interp(1.5));
+        assert_eq!(Err(BezError::ParameterOutOfRange(-0.5)), bez3o.try_slope(-0.5));
+    }
+
+    #[test]
+    fn transformed() {
+        let rotation = AffineMatrix2d::rotation(::std::f64::consts::FRAC_PI_2);
+        let bez3o = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(3.0, 2.0), Point2d::new(4.0, 0.0));
+        let nbez: NBez<f64> = NBez::from_container(vec![Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(4.0, 0.0)]);
+
+        let rotated = bez3o.transformed(&rotation);
+        let rotated_nbez = nbez.transformed(&rotation);
+        for i in 0..11 {
+            let t = i as f64 / 10.0;
+            assert!(rotated.interp(t).unwrap().approx_eq(rotation.transform_point(bez3o.interp(t).unwrap()), 0.000000001));
+            assert!(rotated_nbez.interp(t).unwrap().approx_eq(rotation.transform_point(nbez.interp(t).unwrap()), 0.000000001));
+        }
+        assert!(rotated.interp(1.0).unwrap().approx_eq(Point2d::new(0.0, 4.0), 0.000000001));
+
+        // Composed matrices apply the right-hand side first.
+        let composed = AffineMatrix2d::translation(Vector2d::new(1.0, 0.0)) * AffineMatrix2d::scale(2.0, 3.0);
+        assert_eq!(Point2d::new(3.0, 3.0), composed.transform_point(Point2d::new(1.0, 1.0)));
+        assert_eq!(Point2d::new(1.0, 1.0), (AffineMatrix2d::identity() * AffineMatrix2d::identity()).transform_point(Point2d::new(1.0, 1.0)));
+        assert_eq!(Point2d::new(3.0, 1.0), AffineMatrix2d::shear(2.0, 0.0).transform_point(Point2d::new(1.0, 1.0)));
+    }
+
+    #[test]
+    fn reversed() {
+        let bez3o = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(3.0, 2.0), Point2d::new(4.0, 0.0));
+        assert!(bez3o.reversed().interp(0.25).unwrap().approx_eq(bez3o.interp(0.75).unwrap(), 0.000000001));
+
+        let nbez: NBez<f64, f64> = NBez::from_container(vec![0.0, 1.0, -1.0, 2.0, -2.0]);
+        assert!((nbez.reversed().interp(0.25).unwrap() - nbez.interp(0.75).unwrap()).abs() <= 0.000000001);
+
+        // The trailing point doesn't form a full curve, so it gets dropped.
+        let chain: BezChain<f64, Bez2o<f64, f64>, Vec<f64>> = BezChain::from_container(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+        let reversed = chain.reversed();
+        assert_eq!(&vec![4.0, 3.0, 2.0, 1.0, 0.0], reversed.as_ref());
+
+        let curves: Vec<_> = reversed.iter().collect();
+        let original: Vec<_> = chain.iter().collect();
+        assert_eq!(2, curves.len());
+        assert_eq!(original[1].reversed().as_ref() as &[f64], curves[0].as_ref() as &[f64]);
+        assert_eq!(original[0].reversed().as_ref() as &[f64], curves[1].as_ref() as &[f64]);
+    }
+
+    #[test]
+    fn chain_interp() {
+        let chain: BezChain<f64, Bez3o<f64>, Vec<Point2d<f64>>> = BezChain::from_container(vec![
+            Point2d::new(0.0, 0.0),
+            Point2d::new(1.0, 2.0),
+            Point2d::new(3.0, 2.0),
+            Point2d::new(4.0, 0.0),
+            Point2d::new(5.0, -2.0),
+            Point2d::new(7.0, -2.0),
+            Point2d::new(8.0, 0.0),
+            Point2d::new(9.0, 1.0)
+        ]);
+        assert_eq!(2, chain.segment_count());
+
+        assert_eq!(Some(Point2d::new(0.0, 0.0)), chain.interp(0.0));
+        assert_eq!(Some(Point2d::new(4.0, 0.0)), chain.interp(0.5));
+        assert_eq!(Some(Point2d::new(8.0, 0.0)), chain.interp(1.0));
+        assert_eq!(chain.get(1).unwrap().interp(0.5), chain.interp(0.75));
+        assert_eq!(None, chain.interp(1.5));
+
+        let empty: BezChain<f64, Bez3o<f64>, Vec<Point2d<f64>>> = BezChain::from_container(vec![]);
+        assert_eq!(0, empty.segment_count());
+        assert_eq!(None, empty.interp(0.5));
+    }
+
+    #[test]
+    fn chain_iter() {
+        let points = vec![
+            Point2d::new(0.0, 0.0),
+            Point2d::new(1.0, 2.0),
+            Point2d::new(3.0, 2.0),
+            Point2d::new(4.0, 0.0),
+            Point2d::new(5.0, -2.0),
+            Point2d::new(7.0, -2.0),
+            Point2d::new(8.0, 0.0),
+            Point2d::new(9.0, 1.0)
+        ];
+        let chain: BezChain<f64, Bez3o<f64>, Vec<Point2d<f64>>> = BezChain::from_container(points.clone());
+
+        let mut iter = chain.iter();
+        assert_eq!(2, iter.len());
+        let first = iter.next().unwrap();
+        assert_eq!(&points[0..4], first.as_ref() as &[Point2d<f64>]);
+        assert_eq!(1, iter.len());
+        assert_eq!(&points[3..7], iter.next_back().unwrap().as_ref() as &[Point2d<f64>]);
+        assert!(iter.next().is_none());
+        assert_eq!(2, chain.iter().rev().count());
+
+        let empty: BezChain<f64, Bez3o<f64>, Vec<Point2d<f64>>> = BezChain::from_container(vec![]);
+        assert_eq!(0, empty.iter().len());
+        assert!(empty.iter().next_back().is_none());
+        assert!(empty.get(0).is_none());
+        assert!(chain.get(1).is_some());
+        assert!(chain.get(2).is_none());
+    }
+
+    #[test]
+    fn chain_set_joint() {
+        let mut chain: BezChain<f64, Bez3o<f64>, Vec<Point2d<f64>>> = BezChain::from_container(vec![
+            Point2d::new(0.0, 0.0),
+            Point2d::new(1.0, 2.0),
+            Point2d::new(3.0, 1.0),
+            Point2d::new(4.0, 0.0),
+            Point2d::new(5.0, -1.0),
+            Point2d::new(7.0, -2.0),
+            Point2d::new(8.0, 0.0)
+        ]);
+
+        chain.set_joint(1, Point2d::new(4.0, 3.0), true).unwrap();
+        let incoming = chain.get(0).unwrap().slope(1.0).unwrap();
+        let outgoing = chain.get(1).unwrap().slope(0.0).unwrap();
+        assert_eq!(Point2d::new(4.0, 3.0), chain.get(0).unwrap().end);
+        assert!(incoming.cross(outgoing).abs() <= 0.000000001);
+        assert!(incoming.dot(outgoing) > 0.0);
+
+        // Without maintaining continuity the handles stay where they were.
+        chain.set_joint(2, Point2d::new(8.0, 1.0), false).unwrap();
+        assert_eq!(Point2d::new(7.0, -2.0), chain.get(1).unwrap().ctrl1);
+        assert_eq!(Point2d::new(8.0, 1.0), chain.get(1).unwrap().end);
+
+        assert_eq!(Err(BezError::IndexOutOfRange(3)), chain.set_joint(3, Point2d::new(0.0, 0.0), true));
+    }
+
+    #[test]
+    fn rational_quarter_circle() {
+        let quarter = RatBez::new(
+            vec![Point2d::new(1.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(0.0, 1.0)],
+            vec![1.0, 0.5f64.sqrt(), 1.0]
+        );
+
+        for i in 0..21 {
+            let t = i as f64 / 20.0;
+            let point = quarter.interp(t).unwrap();
+            let slope = quarter.slope(t).unwrap();
+            assert!((Vector2d::from(point).len() - 1.0).abs() <= 0.000000001);
+            // The tangent of a circle is perpendicular to its radius.
+            assert!(Vector2d::from(point).dot(slope).abs() <= 0.000000001);
+        }
+        assert!(quarter.interp(0.5).unwrap().approx_eq(Point2d::new(0.5f64.sqrt(), 0.5f64.sqrt()), 0.000000001));
+
+        // With equal weights, a rational curve is the same as a polynomial one.
+        let points = vec![0.0, 1.0, -1.0, 2.0];
+        let rational: RatBez<f64, f64> = RatBez::new(points.clone(), vec![1.0; 4]);
+        let nbez: NBez<f64, f64> = NBez::from_container(points);
+        for i in 0..11 {
+            let t = i as f64 / 10.0;
+            assert!((rational.interp(t).unwrap() - nbez.interp(t).unwrap()).abs() <= 0.000000001);
+            assert!((rational.slope(t).unwrap() - nbez.slope(t).unwrap()).abs() <= 0.000000001);
+        }
+        assert_eq!(None, rational.interp(1.5));
+    }
+
+    #[test]
+    fn nbez_hodograph() {
+        let nbez: NBez<f64> = NBez::from_container(vec![
+            Point2d::new(0.0, 0.0),
+            Point2d::new(1.0, 2.0),
+            Point2d::new(3.0, -1.0),
+            Point2d::new(4.0, 0.5),
+            Point2d::new(6.0, 2.0)
+        ]);
+        let hodograph = nbez.hodograph();
+        assert_eq!(3, hodograph.order());
+
+        for i in 0..11 {
+            let t = i as f64 / 10.0;
+            let diff = Vector2d::from(hodograph.interp(t).unwrap()) - nbez.slope_unbounded(t);
+            assert!(diff.len() <= 0.000000001);
+        }
+
+        let point: NBez<f64, f64> = NBez::from_container(vec![1.0]);
+        assert_eq!(Some(0.0), point.hodograph().interp(0.5));
+    }
+
+    #[test]
+    fn extrema() {
+        // The curve's y coordinate peaks halfway through, while its x coordinate always increases.
+        let bez3o: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(3.0, 2.0), Point2d::new(4.0, 0.0));
+        let extrema = bez3o.extrema();
+        assert_eq!(3, extrema.len());
+        assert!((extrema[1] - 0.5).abs() <= 0.000000001);
+        assert!(bez3o.slope(extrema[1]).unwrap().y.abs() <= 0.000000001);
+
+        // Roots of the derivative at the endpoints don't get duplicated.
+        assert_eq!(vec![0.0, 1.0], Bez3o::new(0.0, 0.0, 1.0, 1.0).extrema());
+
+        // A double root, counted once.
+        let nbez: NBez<f64, f64> = NBez::from_container(vec![0.0, 1.0, 0.0, 1.0]);
+        let extrema = nbez.extrema();
+        assert_eq!(3, extrema.len());
+        assert!((extrema[1] - 0.5).abs() <= 0.00001);
+    }
+
+    #[test]
+    fn inflections() {
+        let s_curve: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(2.0, -1.0), Point2d::new(3.0, 0.0));
+        let inflections = s_curve.inflections();
+        assert_eq!(1, inflections.len());
+        assert!((inflections[0] - 0.5).abs() <= 0.000000001);
+        assert!(s_curve.curvature(0.25).unwrap() * s_curve.curvature(0.75).unwrap() < 0.0);
+
+        let arc: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(3.0, 2.0), Point2d::new(4.0, 0.0));
+        assert!(arc.inflections().is_empty());
+    }
+
+    #[test]
+    fn offset() {
+        let line: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(2.0, 2.0), Point2d::new(3.0, 3.0));
+        let offset = line.offset(2.0f64.sqrt(), 0.001);
+        assert_eq!(1, offset.len());
+        let expected = [Point2d::new(-1.0, 1.0), Point2d::new(0.0, 2.0), Point2d::new(1.0, 3.0), Point2d::new(2.0, 4.0)];
+        for (point, expected) in (offset[0].as_ref() as &[Point2d<f64>]).iter().zip(expected.iter()) {
+            assert!(point.approx_eq(*expected, 0.000000001));
+        }
+
+        // Every point on an offset curve should be the offset distance away from the original curve.
+        let arc: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(3.0, 2.0), Point2d::new(4.0, 0.0));
+        for &distance in &[0.5, -0.5] {
+            let offset = arc.offset(distance, 0.001);
+            assert!(offset[0].interp(0.0).unwrap().approx_eq(Point2d::new(-2.0 * distance / 5.0f64.sqrt(), distance / 5.0f64.sqrt()), 0.000000001));
+            for piece in &offset {
+                for i in 0..11 {
+                    let point = piece.interp(i as f64 / 10.0).unwrap();
+                    let (_, closest) = arc.project(point, 0.000000001);
+                    assert!((point.distance(closest) - distance.abs()).abs() <= 0.001);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn intersect() {
+        let arc: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(3.0, 2.0), Point2d::new(4.0, 0.0));
+        let line: NBez<f64> = NBez::from_container(vec![Point2d::new(0.0, 1.0), Point2d::new(4.0, 1.0)]);
+
+        // The arc's height is `6t(1 - t)`, so it crosses the line where that's one.
+        let hits = super::intersect(&arc, &line, 0.0000001);
+        assert_eq!(2, hits.len());
+        assert!((hits[0].0 - (1.0 - (1.0f64 / 3.0).sqrt()) / 2.0).abs() <= 0.000001);
+        assert!((hits[1].0 - (1.0 + (1.0f64 / 3.0).sqrt()) / 2.0).abs() <= 0.000001);
+        for &(ta, tb) in &hits {
+            assert!(arc.interp(ta).unwrap().distance(line.interp(tb).unwrap()) <= 0.000001);
+        }
+
+        let above: NBez<f64> = NBez::from_container(vec![Point2d::new(0.0, 3.0), Point2d::new(4.0, 3.0)]);
+        assert!(super::intersect(&arc, &above, 0.0000001).is_empty());
+
+        // The line passes through the loop's crossing, so the hits at both ends of the loop are at the
+        // same point on both curves but are still separate intersections.
+        let looped: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(3.0, 3.0), Point2d::new(-1.0, 3.0), Point2d::new(2.0, 0.0));
+        let vertical: Bez1o<f64> = Bez1o::new(Point2d::new(1.0, -1.0), Point2d::new(1.0, 4.0));
+        let ts = looped.intersect_line(Point2d::new(1.0, 0.0), Vector2d::new(0.0, 1.0));
+        assert_eq!(3, ts.len());
+        let hits = super::intersect(&looped, &vertical, 0.0000001);
+        assert_eq!(3, hits.len());
+        for (&(ta, _), &t) in hits.iter().zip(&ts) {
+            assert!((ta - t).abs() <= 0.000001);
+        }
+    }
+
+    #[test]
+    fn self_intersection() {
+        let looped: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(3.0, 2.0), Point2d::new(-2.0, 2.0), Point2d::new(1.0, 0.0));
+        let (s, t) = looped.self_intersection().unwrap();
+        assert!((s - (2.0 - 3.0f64.sqrt()) / 4.0).abs() <= 0.000000001);
+        assert!((t - (2.0 + 3.0f64.sqrt()) / 4.0).abs() <= 0.000000001);
+        assert!(looped.interp(s).unwrap().approx_eq(Point2d::new(0.5, 0.375), 0.000000001));
+        assert!(looped.interp(t).unwrap().approx_eq(Point2d::new(0.5, 0.375), 0.000000001));
+
+        // This curve's loop would be outside of `0.0` to `1.0`.
+        let arc: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(3.0, 2.0), Point2d::new(4.0, 0.0));
+        assert_eq!(None, arc.self_intersection());
+
+        let s_curve: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(2.0, -1.0), Point2d::new(3.0, 0.0));
+        assert_eq!(None, s_curve.self_intersection());
+    }
+
+    #[test]
+    fn fit_cubic() {
+        let source: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(3.0, 2.0), Point2d::new(4.0, 0.0));
+        let samples: Vec<_> = source.interp_iter(50).collect();
+
+        let curves = super::fit_cubic(&samples, 0.01);
+        assert_eq!(1, curves.len());
+        for sample in &samples {
+            let (_, closest) = curves[0].project(*sample, 0.000000001);
+            assert!(sample.distance(closest) <= 0.01);
+        }
+        assert!(curves[0].start.approx_eq(source.start, 0.000000001));
+        assert!(curves[0].end.approx_eq(source.end, 0.000000001));
+
+        // Nearly straight points shouldn't get split up.
+        let line: Vec<_> = (0..20).map(|i| Point2d::new(i as f64, if i % 2 == 0 {0.0} else {0.001})).collect();
+        assert_eq!(1, super::fit_cubic(&line, 0.01).len());
+
+        // Sharp corners need more than one curve.
+        let corner = vec![Point2d::new(0.0, 0.0), Point2d::new(1.0, 0.0), Point2d::new(2.0, 0.0), Point2d::new(2.0, 1.0), Point2d::new(2.0, 2.0)];
+        let curves = super::fit_cubic(&corner, 0.01);
+        assert!(curves.len() > 1);
+        for point in &corner {
+            assert!(curves.iter().any(|c| point.distance(c.project(*point, 0.000000001).1) <= 0.01));
+        }
+
+        assert!(super::fit_cubic::<f64>(&[Point2d::new(0.0, 0.0)], 0.01).is_empty());
+    }
+
+    #[test]
+    fn catmull_rom() {
+        let points = vec![Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(3.0, 1.0), Point2d::new(4.0, 3.0)];
+        let chain = BezChain::<f64, Bez3o<f64>, Vec<Point2d<f64>>>::from_catmull_rom(&points, 0.0);
+        assert_eq!(3, chain.segment_count());
+        for (i, point) in points.iter().enumerate() {
+            assert_eq!(*point, chain.as_ref()[i * 3]);
+        }
+
+        // Interior tangents are continuous, and parallel to the line between each point's neighbours.
+        let incoming = chain.get(0).unwrap().slope(1.0).unwrap();
+        let outgoing = chain.get(1).unwrap().slope(0.0).unwrap();
+        assert!(incoming.approx_eq(outgoing, 0.000000001));
+        assert!(incoming.approx_eq(Vector2d::new(1.5, 0.5), 0.000000001));
+
+        // Full tension gives straight lines.
+        let chain = BezChain::<f64, Bez3o<f64>, Vec<Point2d<f64>>>::from_catmull_rom(&points, 1.0);
+        assert_eq!(points[0], chain.as_ref()[1]);
+        assert_eq!(points[1], chain.as_ref()[2]);
+    }
+
+    #[test]
+    fn bspline() {
+        let control = vec![Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(3.0, 1.0), Point2d::new(4.0, 3.0), Point2d::new(6.0, 0.0)];
+        let chain = BezChain::<f64, Bez3o<f64>, Vec<Point2d<f64>>>::from_bspline(&control);
+        assert_eq!(2, chain.segment_count());
+
+        // Evaluate the B-spline directly with its basis functions.
+        let basis = |u: f64| [
+            (1.0 - u).powi(3) / 6.0,
+            (3.0 * u.powi(3) - 6.0 * u.powi(2) + 4.0) / 6.0,
+            (-3.0 * u.powi(3) + 3.0 * u.powi(2) + 3.0 * u + 1.0) / 6.0,
+            u.powi(3) / 6.0
+        ];
+        for (segment, curve) in chain.iter().enumerate() {
+            for i in 0..11 {
+                let u = i as f64 / 10.0;
+                let expected = basis(u).iter().zip(&control[segment..segment + 4])
+                    .fold(Point2d::new(0.0, 0.0), |acc, (b, p)| acc + *p * *b);
+                assert!(curve.interp(u).unwrap().approx_eq(expected, 0.000000001));
+            }
+        }
+
+        assert_eq!(0, BezChain::<f64, Bez3o<f64>, Vec<Point2d<f64>>>::from_bspline(&control[..3]).segment_count());
+    }
+
+    #[test]
+    fn point1d() {
+        // A scalar ease-in-out curve.
+        let ease: NBez<f64, Point1d<f64>> = NBez::from_container(vec![Point1d::new(0.0), Point1d::new(0.0), Point1d::new(1.0), Point1d::new(1.0)]);
+        let mut prev = ease.interp(0.0).unwrap();
+        assert_eq!(Point1d::new(0.0), prev);
+        for i in 1..101 {
+            let value = ease.interp(i as f64 / 100.0).unwrap();
+            assert!(value.x >= prev.x);
+            prev = value;
+        }
+        assert_eq!(Point1d::new(1.0), prev);
+        assert_eq!(Point1d::new(0.5), ease.interp(0.5).unwrap());
+
+        assert_eq!(2.5, Vector1d::new(-2.5).len());
+        let (x,): (f64,) = Point1d::new(3.0).into();
+        assert_eq!(3.0, x);
+    }
+
+    #[test]
+    fn point_components() {
+        let mut point = Point3d::new(1.0, 2.0, 3.0);
+        assert_eq!(2.0, point[1]);
+        point[2] = 5.0;
+        assert_eq!(5.0, point.z);
+
+        let array: [f64; 3] = point.into();
+        assert_eq!(array.to_vec(), point.components().collect::<Vec<_>>());
+        assert_eq!(4, Vector4d::new(1.0, 2.0, 3.0, 4.0).components().count());
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 2 is out of range for Point2d with 2 dimensions")]
+    fn point_index_out_of_range() {
+        let _ = Point2d::new(1.0, 2.0)[2];
+    }
+
+    #[test]
+    fn point_display() {
+        assert_eq!("(1.2, 4.6)", format!("{:.1}", Point2d::new(1.23, 4.56)));
+        assert_eq!("(1.5, -2, 0)", format!("{}", Vector3d::new(1.5, -2.0, 0.0)));
+        assert_eq!("(3)", Point1d::new(3.0).to_string());
+    }
+
+    #[test]
+    fn hashable_point() {
+        use std::collections::HashSet;
+
+        let points = [Point2d::new(0.0, 1.0), Point2d::new(2.0, 3.0), Point2d::new(0.0, 1.0), Point2d::new(-0.0, 1.0), Point2d::new(2.0, 3.0 + 1e-15)];
+        let set: HashSet<_> = points.iter().map(|p| HashablePoint(*p)).collect();
+        assert_eq!(3, set.len());
+        assert!(set.contains(&HashablePoint(Point2d::new(2.0, 3.0))));
+
+        let nan = HashablePoint(Vector3d::new(f64::NAN, 0.0, 0.0));
+        assert_eq!(nan, nan);
+    }
+
+    #[test]
+    fn nbez_elevate_to() {
+        let cubic: NBez<f64, f64> = NBez::from_container(vec![0.0, 1.0, -1.0, 2.0]);
+        let elevated = cubic.elevate_to(6);
+        assert_eq!(6, elevated.order());
+        for i in 0..31 {
+            let t = i as f64 / 30.0;
+            assert!((cubic.interp(t).unwrap() - elevated.interp(t).unwrap()).abs() <= 0.000000001);
+        }
+
+        assert_eq!(3, cubic.elevate_to(2).order());
+        assert_eq!(&[0.0, 1.0, -1.0, 2.0], cubic.elevate_to(3).as_ref() as &[f64]);
+    }
+
+    #[test]
+    fn nbez_blend() {
+        let a: NBez<f64, f64> = NBez::from_container(vec![0.0, 1.0, -1.0, 2.0]);
+        let b: NBez<f64, f64> = NBez::from_container(vec![2.0, 2.0, 0.0, 4.0]);
+        let line: NBez<f64, f64> = NBez::from_container(vec![1.0, 3.0]);
+
+        assert_eq!(&[0.0, 1.0, -1.0, 2.0], a.blend(&b, 0.0).as_ref() as &[f64]);
+        assert_eq!(&[2.0, 2.0, 0.0, 4.0], a.blend(&b, 1.0).as_ref() as &[f64]);
+        assert_eq!(&[1.0, 1.5, -0.5, 3.0], a.blend(&b, 0.5).as_ref() as &[f64]);
+
+        // Curves of different orders blend the same as the curves they interpolate like.
+        let blended = a.blend(&line, 0.5);
+        assert_eq!(3, blended.order());
+        for i in 0..11 {
+            let t = i as f64 / 10.0;
+            let expected = (a.interp(t).unwrap() + line.interp(t).unwrap()) / 2.0;
+            assert!((blended.interp(t).unwrap() - expected).abs() <= 0.000000001);
+        }
+    }
+
+    #[test]
+    fn chain_contains() {
+        // A circle of radius one, made of four quarter-circle cubics going counter-clockwise.
+        let k = 0.5522847498;
+        let mut circle = vec![
+            Point2d::new(1.0, 0.0), Point2d::new(1.0, k), Point2d::new(k, 1.0),
+            Point2d::new(0.0, 1.0), Point2d::new(-k, 1.0), Point2d::new(-1.0, k),
+            Point2d::new(-1.0, 0.0), Point2d::new(-1.0, -k), Point2d::new(-k, -1.0),
+            Point2d::new(0.0, -1.0), Point2d::new(k, -1.0), Point2d::new(1.0, -k)
+        ];
+        let mut twice = circle.clone();
+        twice.extend_from_slice(&circle);
+        circle.push(Point2d::new(1.0, 0.0));
+        twice.push(Point2d::new(1.0, 0.0));
+
+        let chain: BezChain<f64, Bez3o<f64>, Vec<Point2d<f64>>> = BezChain::from_container(circle);
+        // The ray from the center passes exactly through the first point.
+        assert_eq!(1, chain.winding_number(Point2d::new(0.0, 0.0)));
+        assert!(chain.contains(Point2d::new(0.0, 0.0), FillRule::NonZero));
+        assert!(chain.contains(Point2d::new(0.5, -0.5), FillRule::EvenOdd));
+        assert!(!chain.contains(Point2d::new(0.9, 0.9), FillRule::NonZero));
+        assert!(!chain.contains(Point2d::new(-2.0, 0.0), FillRule::EvenOdd));
+        // The ray touches the top of the circle without crossing it.
+        assert!(!chain.contains(Point2d::new(-2.0, 1.0), FillRule::NonZero));
+
+        let reversed = chain.reversed();
+        assert_eq!(-1, reversed.winding_number(Point2d::new(0.0, 0.5)));
+
+        let twice: BezChain<f64, Bez3o<f64>, Vec<Point2d<f64>>> = BezChain::from_container(twice);
+        assert_eq!(2, twice.winding_number(Point2d::new(0.0, 0.5)));
+        assert!(twice.contains(Point2d::new(0.0, 0.5), FillRule::NonZero));
+        assert!(!twice.contains(Point2d::new(0.0, 0.5), FillRule::EvenOdd));
+
+        // Rays that pass through a vertex or run along a horizontal edge.
+        let triangle = BezChain::from_polyline(&[
+            Point2d::new(0.0f64, 0.0), Point2d::new(4.0, 0.0), Point2d::new(2.0, 2.0), Point2d::new(0.0, 0.0)
+        ]);
+        assert_eq!(0, triangle.winding_number(Point2d::new(1.0, 2.0)));
+        assert_eq!(0, triangle.winding_number(Point2d::new(-1.0, 0.0)));
+        assert_eq!(0, triangle.winding_number(Point2d::new(5.0, 0.0)));
+        assert_eq!(1, triangle.winding_number(Point2d::new(2.0, 1.0)));
+        assert_eq!(1, triangle.winding_number(Point2d::new(1.0, 0.5)));
+
+        let square = BezChain::from_polyline(&[
+            Point2d::new(0.0f64, 0.0), Point2d::new(2.0, 0.0), Point2d::new(2.0, 2.0), Point2d::new(0.0, 2.0), Point2d::new(0.0, 0.0)
+        ]);
+        for &rule in &[FillRule::NonZero, FillRule::EvenOdd] {
+            assert!(!square.contains(Point2d::new(-1.0, 2.0), rule));
+            assert!(!square.contains(Point2d::new(-1.0, 0.0), rule));
+            assert!(!square.contains(Point2d::new(3.0, 1.0), rule));
+            assert!(square.contains(Point2d::new(1.0, 1.0), rule));
+        }
+        assert_eq!(-1, square.reversed().winding_number(Point2d::new(1.0, 1.0)));
+    }
+
+    #[test]
+    fn chain_walk() {
+        let chain: BezChain<f64, Bez3o<f64>, _> = BezChain::from_container(vec![
+            Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(2.0, 1.0), Point2d::new(3.0, 0.0),
+            Point2d::new(4.0, -1.0), Point2d::new(5.0, -1.0), Point2d::new(6.0, 0.0)
+        ]);
+        let length = chain.iter().map(|c| c.arc_length(0.0000001)).sum::<f64>();
+
+        let walked: Vec<_> = chain.walk(0.1, 0.0000001).collect();
+        assert_eq!((length / 0.1) as usize + 1, walked.len());
+        assert_eq!(Point2d::new(0.0, 0.0), walked[0].0);
+        for pair in walked.windows(2) {
+            // Over such a short step, the distance between points is very close to the length along
+            // the curve between them.
+            assert!((pair[0].0.distance(pair[1].0) - 0.1).abs() <= 0.0001);
+        }
+        for &(_, tangent) in &walked {
+            assert!((tangent.len() - 1.0).abs() <= 0.000000001);
+        }
+
+        let with_end: Vec<_> = chain.walk_with_end(0.1, 0.0000001).collect();
+        assert_eq!(walked.len() + 1, with_end.len());
+        assert_eq!(Point2d::new(6.0, 0.0), with_end[with_end.len() - 1].0);
+    }
+
+    #[test]
+    fn tangent_normal() {
+        let line: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(3.0, 4.0), Point2d::new(4.5, 6.0), Point2d::new(6.0, 8.0));
+        for i in 0..11 {
+            let t = i as f64 / 10.0;
+            let tangent = line.tangent(t).unwrap();
+            let normal = line.normal(t).unwrap();
+            assert!(tangent.approx_eq(Vector2d::new(0.6, 0.8), 0.000000001));
+            assert!(normal.approx_eq(Vector2d::new(-0.8, 0.6), 0.000000001));
+            assert!(tangent.dot(normal).abs() <= 0.000000001);
+        }
+        assert_eq!(None, line.tangent(1.5));
+
+        let nbez: NBez<f64, Point2d<f64>, _> = NBez::from_container(vec![Point2d::new(0.0, 0.0), Point2d::new(0.0, 2.0), Point2d::new(0.0, 3.0)]);
+        assert!(nbez.normal(0.5).unwrap().approx_eq(Vector2d::new(-1.0, 0.0), 0.000000001));
+
+        // The slope is zero at the start of a curve with a repeated first control point.
+        let cusp: Bez2o<f64> = Bez2o::new(Point2d::new(0.0, 0.0), Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0));
+        assert_eq!(Vector2d::new(0.0, 0.0), cusp.tangent(0.0).unwrap());
+        assert_eq!(Vector2d::new(0.0, 0.0), cusp.normal(0.0).unwrap());
+    }
+
+    #[test]
+    fn control_hull() {
+        let curve: NBez<f64, Point2d<f64>, _> = NBez::from_container(vec![
+            Point2d::new(0.0, 0.0), Point2d::new(1.0, 3.0), Point2d::new(2.0, 1.0),
+            Point2d::new(3.0, 2.0), Point2d::new(4.0, -1.0), Point2d::new(1.0, 0.5)
+        ]);
+        let hull = curve.control_hull();
+        assert_eq!(&[Point2d::new(0.0, 0.0), Point2d::new(4.0, -1.0), Point2d::new(3.0, 2.0), Point2d::new(1.0, 3.0)], &hull[..]);
+
+        // Every control point is inside of or on the hull.
+        for &p in AsRef::<[Point2d<f64>]>::as_ref(&curve) {
+            for (i, &a) in hull.iter().enumerate() {
+                let b = hull[(i + 1) % hull.len()];
+                assert!(Vector2d::from(b - a).cross(Vector2d::from(p - a)) >= 0.0);
+            }
+        }
+
+        let triangle: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(2.0, 2.0), Point2d::new(4.0, 0.0), Point2d::new(2.0, 0.0));
+        assert_eq!(3, triangle.control_hull().len());
+
+        let line: Bez3o<f64> = Bez3o::new(Point2d::new(1.0, 1.0), Point2d::new(0.0, 0.0), Point2d::new(3.0, 3.0), Point2d::new(2.0, 2.0));
+        assert_eq!(vec![Point2d::new(0.0, 0.0), Point2d::new(3.0, 3.0)], line.control_hull());
+    }
+
+    #[test]
+    fn split_many() {
+        let curve: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 3.0), Point2d::new(3.0, -2.0), Point2d::new(4.0, 1.0));
+        let pieces = curve.split_many(&[0.25, 0.5, 0.75]);
+        assert_eq!(4, pieces.len());
+
+        for (i, piece) in pieces.iter().enumerate() {
+            let original = curve.subsegment(i as f64 * 0.25, (i + 1) as f64 * 0.25).unwrap();
+            assert!(curve_approx_eq(piece, &original, 11, 0.000000001));
+        }
+
+        // Unsorted, duplicate, and out of range parameters.
+        let pieces = curve.split_many(&[0.5, 1.0, -0.5, 0.25, 0.5, 0.0]);
+        assert_eq!(3, pieces.len());
+        assert!(pieces[1].interp(1.0).unwrap().approx_eq(curve.interp(0.5).unwrap(), 0.000000001));
+        assert_eq!(1, curve.split_many(&[]).len());
+    }
+
+    #[test]
+    fn subsegment() {
+        let curve: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 3.0), Point2d::new(3.0, -2.0), Point2d::new(4.0, 1.0));
+        let sub = curve.subsegment(0.3, 0.7).unwrap();
+        assert!(sub.interp(0.0).unwrap().approx_eq(curve.interp(0.3).unwrap(), 0.000000001));
+        assert!(sub.interp(0.5).unwrap().approx_eq(curve.interp(0.5).unwrap(), 0.000000001));
+        assert!(sub.interp(1.0).unwrap().approx_eq(curve.interp(0.7).unwrap(), 0.000000001));
+
+        let reversed = curve.subsegment(0.7, 0.3).unwrap();
+        assert!(curve_approx_eq(&reversed, &sub.reversed(), 11, 0.000000001));
+
+        let end = curve.subsegment(1.0, 1.0).unwrap();
+        assert!(end.interp(0.5).unwrap().approx_eq(Point2d::new(4.0, 1.0), 0.000000001));
+        assert!(curve.subsegment(0.5, 1.5).is_none());
+    }
+
+    #[test]
+    fn chain_builder() {
+        let mut builder = ChainBuilder::new(Point2d::new(0.0, 0.0));
+        builder.cubic_to(Point2d::new(1.0, 1.0), Point2d::new(2.0, 1.0), Point2d::new(3.0, 0.0))
+               .line_to(Point2d::new(3.0, -3.0));
+        assert_eq!(Point2d::new(3.0, -3.0), builder.current());
+
+        let chain = builder.build();
+        assert_eq!(2, chain.segment_count());
+        // Every point is used by a full curve.
+        assert_eq!(7, chain.as_ref().len());
+        assert_eq!(Point2d::new(3.0, 0.0), chain.get(0).unwrap().end);
+
+        let line = chain.get(1).unwrap();
+        assert_eq!(Point2d::new(3.0, 0.0), line.start);
+        assert!(line.interp(0.25).unwrap().approx_eq(Point2d::new(3.0, -0.75), 0.000000001));
+    }
+
+    #[test]
+    fn arc() {
+        use std::f64::consts::PI;
+
+        let center = Point2d::new(1.0, -1.0);
+        let circle = BezChain::arc(center, 2.0, 0.0, 2.0 * PI);
+        assert_eq!(4, circle.segment_count());
+        assert!(circle.interp(1.0).unwrap().approx_eq(Point2d::new(3.0, -1.0), 0.000000001));
+        for i in 0..101 {
+            let radius = circle.interp(i as f64 / 100.0).unwrap().distance(center);
+            assert!((radius - 2.0).abs() <= 0.001);
+        }
+
+        // Negative sweeps go clockwise.
+        let quarter = BezChain::arc(Point2d::new(0.0, 0.0), 1.0, 0.0, -PI / 2.0);
+        assert_eq!(1, quarter.segment_count());
+        assert!(quarter.interp(1.0).unwrap().approx_eq(Point2d::new(0.0, -1.0), 0.000000001));
+        assert!(quarter.interp(0.5).unwrap().y < 0.0);
+
+        let slice = BezChain::arc(Point2d::new(0.0, 0.0), 1.0, PI / 4.0, PI);
+        assert_eq!(2, slice.segment_count());
+        assert!(slice.interp(0.5).unwrap().approx_eq(Point2d::new(-(0.5f64.sqrt()), 0.5f64.sqrt()), 0.000000001));
+    }
+
+    #[test]
+    fn pointn() {
+        let v: VectorN<f64, 6> = VectorN::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert!((v.len() - 91.0f64.sqrt()).abs() <= 0.000000001);
+        assert!((v.normalize().len() - 1.0).abs() <= 0.000000001);
+        assert_eq!(VectorN::new([2.0, 4.0, 6.0, 8.0, 10.0, 12.0]), v + v);
+        assert_eq!(VectorN::new([0.5, 1.0, 1.5, 2.0, 2.5, 3.0]), v / 2.0);
+        assert_eq!(5.0, v[4]);
+        let array: [f64; 6] = v.into();
+        assert_eq!([1.0, 2.0, 3.0, 4.0, 5.0, 6.0], array);
+
+        let start: PointN<f64, 6> = PointN::new([0.0; 6]);
+        assert!((start.distance(PointN::from(v)) - v.len()).abs() <= 0.000000001);
+
+        let curve: NBez<f64, PointN<f64, 6>, _> = NBez::from_container(vec![start, PointN::from(v)]);
+        assert!(curve.interp(0.5).unwrap().approx_eq(PointN::from(v / 2.0), 0.000000001));
+        assert!(curve.slope(0.5).unwrap().approx_eq(v, 0.000000001));
+    }
+
+    // Defines `MAX_ORDER`, `MaxOrderBez`, `MAX_DIMS`, and `MaxDimsPoint`, depending on what the build
+    // script was configured with.
+    include!(concat!(env!("OUT_DIR"), "/max_order.rs"));
+
+    #[test]
+    fn max_order() {
+        assert_eq!(MAX_ORDER, MaxOrderBez::<f64, Point2d<f64>>::order_static());
+
+        let points: Vec<_> = (0..MAX_ORDER + 1).map(|i| Point2d::new(i as f64, 0.0)).collect();
+        let curve = MaxOrderBez::from_slice(&points).unwrap();
+        assert!(curve.interp(0.5).unwrap().approx_eq(Point2d::new(MAX_ORDER as f64 / 2.0, 0.0), 0.000000001));
+
+        // The generated coefficients match the ones `NBez` uses.
+        let coeffs: Vec<f64> = (0..MAX_ORDER + 1).map(|i| ((i * 7) % 5) as f64 - 2.0).collect();
+        let nbez: NBez<f64, f64, _> = NBez::from_container(coeffs.clone());
+        test_poly_eq(&nbez, &MaxOrderBez::from_slice(&coeffs).unwrap());
+        test_poly_slope_eq(&nbez, &MaxOrderBez::from_slice(&coeffs).unwrap());
+    }
+
+    #[test]
+    fn max_dims() {
+        let components: Vec<f64> = (0..MAX_DIMS).map(|i| i as f64).collect();
+        let mut point = MaxDimsPoint::default();
+        point.as_mut_slice().copy_from_slice(&components);
+        assert_eq!(MAX_DIMS, point.components().count());
+        assert_eq!(&components[..], point.as_slice());
+        assert_eq!(point, point - MaxDimsPoint::default());
+    }
+
+    #[test]
+    fn bez3o_hodograph() {
+        let curve: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 3.0), Point2d::new(3.0, -2.0), Point2d::new(4.0, 1.0));
+        let hodograph = curve.hodograph();
+        assert_eq!(Point2d::new(3.0, 9.0), hodograph.start);
+
+        for i in 0..11 {
+            let t = i as f64 / 10.0;
+            let slope = curve.slope(t).unwrap();
+            assert!(Vector2d::from(hodograph.interp(t).unwrap()).approx_eq(slope, 0.000000001));
+        }
+    }
+
+    #[test]
+    fn split_monotone() {
+        let curve: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(3.0, 3.0), Point2d::new(-1.0, -2.0), Point2d::new(4.0, 1.0));
+
+        let y_pieces = curve.split_monotone_y();
+        assert_eq!(3, y_pieces.len());
+        for piece in &y_pieces {
+            let (start, end) = (piece.interp(0.0).unwrap().y, piece.interp(1.0).unwrap().y);
+            let (lo, hi) = (start.min(end), start.max(end));
+            for i in 1..20 {
+                let y = piece.interp(i as f64 / 20.0).unwrap().y;
+                assert!(lo - 0.000000001 <= y && y <= hi + 0.000000001);
+            }
+        }
+
+        let x_pieces = curve.split_monotone_x();
+        assert_eq!(3, x_pieces.len());
+        for piece in &x_pieces {
+            let (start, end) = (piece.interp(0.0).unwrap().x, piece.interp(1.0).unwrap().x);
+            let (lo, hi) = (start.min(end), start.max(end));
+            for i in 1..20 {
+                let x = piece.interp(i as f64 / 20.0).unwrap().x;
+                assert!(lo - 0.000000001 <= x && x <= hi + 0.000000001);
+            }
+        }
+
+        let line: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(2.0, 2.0), Point2d::new(3.0, 3.0));
+        assert_eq!(1, line.split_monotone_y().len());
+    }
+
+    #[test]
+    fn solve_axis() {
+        let curve: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(3.0, 3.0), Point2d::new(1.0, -2.0), Point2d::new(4.0, 1.0));
+
+        let ts = curve.solve_axis(0, 2.0);
+        assert_eq!(1, ts.len());
+        assert!((curve.interp(ts[0]).unwrap().x - 2.0).abs() <= 0.000000001);
+
+        // The curve starts on the line, and touches it again at `t = 0.75`.
+        let ts = curve.solve_axis(1, 0.0);
+        assert_eq!(2, ts.len());
+        assert_eq!(0.0, ts[0]);
+        assert!((ts[1] - 0.75).abs() <= 0.000001);
+        for &t in &ts {
+            assert!(curve.interp(t).unwrap().y.abs() <= 0.000000001);
+        }
+
+        assert!(curve.solve_axis(0, 5.0).is_empty());
+    }
+
+    #[test]
+    fn lerp() {
+        let (a, b) = (Point2d::new(1.0, -2.0), Point2d::new(5.0, 4.0));
+        let mid = super::lerp(a, b, 0.5);
+        assert_eq!(Point2d::new(3.0, 1.0), mid);
+        // The midpoint is on the line through `a` and `b`.
+        assert_eq!(0.0, Vector2d::from(b - a).cross(Vector2d::from(mid - a)));
+
+        assert_eq!(a, super::lerp(a, b, 0.0));
+        assert_eq!(Point2d::new(9.0, 10.0), super::lerp(a, b, 2.0));
+        assert_eq!(Vector2d::new(0.5, 0.5), super::lerp(Vector2d::new(0.0, 0.0), Vector2d::new(1.0, 1.0), 0.5));
+
+        assert_eq!(2.5, lerp_scalar(2.0, 4.0, 0.25));
+        assert_eq!(0.0, lerp_scalar(2.0, 4.0, -1.0));
+    }
+
+    #[test]
+    fn vector_angles() {
+        use std::f64::consts::PI;
+
+        let x = Vector2d::new(1.0, 0.0);
+        assert!(x.rotate(PI / 2.0).approx_eq(Vector2d::new(0.0, 1.0), 0.000000001));
+        assert!(Vector2d::new(1.0, 1.0).rotate(-PI / 4.0).approx_eq(Vector2d::new(2.0f64.sqrt(), 0.0), 0.000000001));
+
+        assert!((Vector2d::new(-1.0, 1.0).angle() - PI * 0.75).abs() <= 0.000000001);
+        assert!((Vector2d::new(0.0, -2.0).angle() - -PI / 2.0).abs() <= 0.000000001);
+
+        assert!((x.angle_between(Vector2d::new(0.0, 3.0)) - PI / 2.0).abs() <= 0.000000001);
+        assert!((Vector2d::new(0.0, 3.0).angle_between(x) - -PI / 2.0).abs() <= 0.000000001);
+        assert!((x.angle_between(Vector2d::new(-1.0, 0.0)).abs() - PI).abs() <= 0.000000001);
+    }
+
+    #[test]
+    fn vector_reflect_project() {
+        let normal = Vector2d::new(0.0, 1.0);
+        assert_eq!(Vector2d::new(1.0, 1.0), Vector2d::new(1.0, -1.0).reflect(normal));
+        let diagonal = Vector2d::new(1.0, 1.0).normalize();
+        assert!(Vector2d::new(1.0, 0.0).reflect(diagonal).approx_eq(Vector2d::new(0.0, -1.0), 0.000000001));
+
+        assert_eq!(Vector2d::new(3.0, 0.0), Vector2d::new(3.0, 4.0).project_onto(Vector2d::new(2.0, 0.0)));
+        assert_eq!(Vector3d::new(1.0, 1.0, 0.0), Vector3d::new(2.0, 0.0, 5.0).project_onto(Vector3d::new(1.0, 1.0, 0.0)));
+        assert_eq!(Vector2d::new(0.0, 0.0), Vector2d::new(3.0, 4.0).project_onto(Vector2d::new(0.0, 0.0)));
+    }
+
+    #[test]
+    fn point_default() {
+        assert_eq!(0.0, Vector3d::<f64>::default().len());
+        assert_eq!(Point2d::new(0.0, 0.0), Point2d::<f32>::default());
+
+        #[derive(Default)]
+        struct Labeled {
+            position: Point2d<f64>
+        }
+        assert_eq!(Point2d::new(0.0, 0.0), Labeled::default().position);
+    }
+
+    #[test]
+    fn curves_approx_eq() {
+        let bez2o: Bez2o<f64> = Bez2o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(2.0, 0.0));
+        let elevated = bez2o.elevate();
+        assert!(curve_approx_eq(&bez2o, &elevated, 20, 0.000000001));
+
+        let nudged: Bez2o<f64> = Bez2o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.001), Point2d::new(2.0, 0.0));
+        assert!(!curve_approx_eq(&bez2o, &nudged, 20, 0.000000001));
+        assert!(curve_approx_eq(&bez2o, &nudged, 20, 0.001));
+        // Only the ends get sampled, and those are the same.
+        assert!(curve_approx_eq(&bez2o, &nudged, 2, 0.000000001));
+    }
+
+    #[test]
+    fn signed_area() {
+        let mut builder = ChainBuilder::new(Point2d::new(0.0, 0.0));
+        builder.line_to(Point2d::new(2.0, 0.0))
+               .cubic_to(Point2d::new(2.5, 0.5), Point2d::new(2.5, 1.0), Point2d::new(2.0, 1.5))
+               .line_to(Point2d::new(0.0, 1.5))
+               .line_to(Point2d::new(0.0, 0.0));
+        let square = builder.build();
+
+        // Compare against the area of a fine polygon approximating the shape.
+        let polygon: Vec<_> = square.iter().flat_map(|c| c.flatten(0.0000001)).collect();
+        let polygon_area = (0..polygon.len()).fold(0.0f64, |acc, i| {
+            let (a, b) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+            acc + (a.x * b.y - b.x * a.y) / 2.0
+        });
+        assert!(square.signed_area() > 3.0);
+        assert!((square.signed_area() - polygon_area).abs() <= 0.00001);
+        assert!((square.reversed().signed_area() + square.signed_area()).abs() <= 0.000000001);
+
+        let circle = BezChain::arc(Point2d::new(3.0, -2.0), 2.0f64, 0.0, -2.0 * ::std::f64::consts::PI);
+        assert!((circle.signed_area() + 4.0 * ::std::f64::consts::PI).abs() <= 0.01);
+
+        // Open chains and curves get closed with a straight line.
+        let half: Bez3o<f64> = square.get(1).unwrap();
+        let line_area = Bez1o::new(Point2d::new(2.0, 0.0), Point2d::new(2.0, 1.5)).signed_area();
+        assert_eq!(0.0, line_area);
+        assert!(half.signed_area() > 0.0);
+        let open: BezChain<f64, Bez3o<f64>, _> = BezChain::from_container(AsRef::<[Point2d<f64>]>::as_ref(&half).to_vec());
+        assert!((open.signed_area() - half.signed_area()).abs() <= 0.000000001);
+    }
+
+    #[test]
+    fn centroid() {
+        // A rounded rectangle, which is symmetric about its center.
+        let mut builder = ChainBuilder::new(Point2d::new(1.0, 0.0));
+        builder.line_to(Point2d::new(3.0, 0.0))
+               .cubic_to(Point2d::new(4.0, 0.0), Point2d::new(4.0, 2.0), Point2d::new(3.0, 2.0))
+               .line_to(Point2d::new(1.0, 2.0))
+               .cubic_to(Point2d::new(0.0, 2.0), Point2d::new(0.0, 0.0), Point2d::new(1.0, 0.0));
+        let chain = builder.build();
+        assert!(chain.centroid().unwrap().approx_eq(Point2d::new(2.0, 1.0), 0.000000001));
+        assert!(chain.reversed().centroid().unwrap().approx_eq(Point2d::new(2.0, 1.0), 0.000000001));
+
+        let circle = BezChain::arc(Point2d::new(-1.0, 5.0), 3.0, 0.3, 2.0 * ::std::f64::consts::PI);
+        assert!(circle.centroid().unwrap().approx_eq(Point2d::new(-1.0, 5.0), 0.000001));
+
+        // A half circle's centroid is `4r / 3π` from its flat edge.
+        let half = BezChain::arc(Point2d::new(0.0, 0.0), 1.0, 0.0, ::std::f64::consts::PI);
+        let expected = 4.0 / (3.0 * ::std::f64::consts::PI);
+        assert!(half.centroid().unwrap().approx_eq(Point2d::new(0.0, expected), 0.001));
+
+        // A line back and forth over itself encloses no area.
+        let mut builder = ChainBuilder::new(Point2d::new(0.0, 0.0));
+        builder.line_to(Point2d::new(1.0, 1.0)).line_to(Point2d::new(0.0, 0.0));
+        assert_eq!(None, builder.build().centroid());
+    }
+
+    #[test]
+    fn intersect_clip() {
+        let wave: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 4.0), Point2d::new(3.0, -4.0), Point2d::new(4.0, 0.0));
+        let rise: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.3), Point2d::new(1.5, 0.6), Point2d::new(2.5, -0.5), Point2d::new(4.0, -0.2));
+
+        let (subdivided, subdivided_steps) = intersect::intersect_counted(&wave, &rise, 0.000001);
+        let (clipped, clipped_steps) = intersect::intersect_clip_counted(&wave, &rise, 0.000001);
+        assert_eq!(3, clipped.len());
+        assert_eq!(subdivided.len(), clipped.len());
+        for (&(sa, sb), &(ca, cb)) in subdivided.iter().zip(&clipped) {
+            assert!((sa - ca).abs() <= 0.00001 && (sb - cb).abs() <= 0.00001);
+            assert!(wave.interp(ca).unwrap().distance(rise.interp(cb).unwrap()) <= 0.00001);
+        }
+        assert!(clipped_steps * 4 < subdivided_steps);
+
+        let line: NBez<f64> = NBez::from_container(vec![Point2d::new(0.0, 1.0), Point2d::new(4.0, 1.0)]);
+        assert_eq!(2, super::intersect_clip(&wave, &line, 0.0000001).len());
+        let above: NBez<f64> = NBez::from_container(vec![Point2d::new(0.0, 3.0), Point2d::new(4.0, 3.0)]);
+        assert!(super::intersect_clip(&wave, &above, 0.0000001).is_empty());
+
+        // Splitting the loop ends a piece right at the crossing in the middle, which has to survive
+        // being clipped.
+        let looped: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(3.0, 3.0), Point2d::new(-1.0, 3.0), Point2d::new(2.0, 0.0));
+        let vertical: Bez1o<f64> = Bez1o::new(Point2d::new(1.0, -1.0), Point2d::new(1.0, 4.0));
+        let ts = looped.intersect_line(Point2d::new(1.0, 0.0), Vector2d::new(0.0, 1.0));
+        let hits = super::intersect_clip(&looped, &vertical, 0.0000001);
+        assert_eq!(3, hits.len());
+        for (&(ta, _), &t) in hits.iter().zip(&ts) {
+            assert!((ta - t).abs() <= 0.000001);
+        }
+    }
+
+    #[test]
+    fn intersect_overlapping() {
+        let curve: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 4.0), Point2d::new(3.0, -4.0), Point2d::new(4.0, 0.0));
+        let (start, end) = curve.split(0.5).unwrap();
+
+        // Overlapping curves meet along a whole stretch, which gets reported as its two ends rather
+        // than subdividing forever.
+        assert_eq!(vec![(0.0, 0.0), (1.0, 1.0)], super::intersect_clip(&curve, &curve, 0.0000001));
+        assert_eq!(vec![(0.0, 0.0), (1.0, 1.0)], super::intersect(&curve, &curve, 0.00001));
+        let start_hits = super::intersect_clip(&curve, &start, 0.00001);
+        assert_eq!(2, start_hits.len());
+        assert!(start_hits[0].0.abs() <= 0.0001 && start_hits[0].1.abs() <= 0.0001);
+        assert!((start_hits[1].0 - 0.5).abs() <= 0.0001 && (start_hits[1].1 - 1.0).abs() <= 0.0001);
+
+        // A curve that crosses itself meets itself along the whole curve and at the crossing, once with
+        // each pass through it.
+        let looped: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(5.0, 3.0), Point2d::new(-2.0, 3.0), Point2d::new(2.0, -1.0));
+        for hits in &[super::intersect_clip(&looped, &looped, 0.00001), super::intersect(&looped, &looped, 0.00001)] {
+            assert_eq!(4, hits.len());
+            let (ta, tb) = hits[1];
+            assert!((ta - hits[2].1).abs() <= 0.0001 && (tb - hits[2].0).abs() <= 0.0001);
+            assert!(looped.interp(ta).unwrap().distance(looped.interp(tb).unwrap()) <= 0.0001);
+        }
+
+        // Curves that only share an end still meet at a single point.
+        let touching = super::intersect_clip(&start, &end, 0.0000001);
+        assert_eq!(1, touching.len());
+        assert!((touching[0].0 - 1.0).abs() <= 0.000001 && touching[0].1.abs() <= 0.000001);
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn par_samples() {
+        let curve: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 3.0), Point2d::new(3.0, -2.0), Point2d::new(4.0, 1.0));
+        for &count in &[0, 1, 2, 1000] {
+            assert_eq!(curve.samples(count).collect::<Vec<_>>(), curve.par_samples(count));
+        }
+
+        let curves: Vec<NBez<f64>> = (0..64).map(|i| NBez::from_container(vec![
+            Point2d::new(0.0, i as f64), Point2d::new(1.0, -(i as f64)), Point2d::new(2.0, 0.5)
+        ])).collect();
+        let serial: Vec<Vec<_>> = curves.iter().map(|c| c.samples(100).collect()).collect();
+        assert_eq!(serial, par_sample_many(&curves, 100));
+    }
+
+    #[test]
+    fn slice_views() {
+        let mut point = Point3d::new(1.0, 2.0, 3.0);
+        point.as_mut()[1] = 7.0;
+        assert_eq!(7.0, point.y);
+        point.z = -4.0;
+        assert_eq!(&[1.0, 7.0, -4.0], point.as_ref());
+
+        let mut curve: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(2.0, 1.0), Point2d::new(3.0, 0.0));
+        curve.as_mut()[2] = Point2d::new(5.0, -5.0);
+        assert_eq!(Point2d::new(5.0, -5.0), curve.ctrl1);
+        curve.end = Point2d::new(9.0, 9.0);
+        assert_eq!(Point2d::new(9.0, 9.0), curve.as_ref()[3]);
+    }
+
+    #[test]
+    fn interp_batch() {
+        let curve: NBez<f64> = NBez::from_container(vec![
+            Point2d::new(0.0, 0.0), Point2d::new(1.0, 4.0), Point2d::new(2.0, -3.0), Point2d::new(5.0, 2.0), Point2d::new(6.0, 0.0)
+        ]);
+        let ts: Vec<f64> = (0..=50).map(|i| -0.5 + i as f64 * 0.04).collect();
+        let mut out = vec![Point2d::new(0.0, 0.0); ts.len()];
+        curve.interp_batch(&ts, &mut out);
+
+        for (&t, point) in ts.iter().zip(out.iter()) {
+            assert_eq!(curve.interp_unbounded(t), *point);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn interp_batch_length_mismatch() {
+        let curve: NBez<f64> = NBez::from_container(vec![Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0)]);
+        curve.interp_batch(&[0.0, 0.5], &mut [Point2d::new(0.0, 0.0)]);
+    }
+
+    #[test]
+    fn power_basis() {
+        let cubic: Bez3o<f64, Point3d<f64>> = Bez3o::new(Point3d::new(0.0, 1.0, -2.0), Point3d::new(1.0, 4.0, 0.5), Point3d::new(3.0, -2.0, 1.0), Point3d::new(4.0, 1.0, 3.0));
+        let cubic_coeffs = cubic.to_power_basis();
+        let sextic: Bez6o<f64> = Bez6o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 3.0), Point2d::new(2.0, -1.0),
+            Point2d::new(3.0, 5.0), Point2d::new(4.0, -2.0), Point2d::new(5.0, 4.0), Point2d::new(6.0, 0.0));
+        let sextic_coeffs = sextic.to_power_basis();
+
+        for i in 0..=100 {
+            let t = -0.5 + i as f64 * 0.02;
+            assert!(cubic.interp_unbounded(t).approx_eq(eval_power_basis(&cubic_coeffs, t), 0.000000001));
+            assert!(sextic.interp_unbounded(t).approx_eq(eval_power_basis(&sextic_coeffs, t), 0.000000001));
+        }
+        assert_eq!(Point2d::new(0.0, 0.0), eval_power_basis::<Point2d<f64>, f64>(&[], 0.5));
+    }
+
+    #[test]
+    fn obb() {
+        let diagonal: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(3.0, 3.0), Point2d::new(4.0, 4.0));
+        let (center, along, across) = diagonal.obb();
+        assert!(center.approx_eq(Point2d::new(2.0, 2.0), 0.000000001));
+        assert!((along.len() - 8.0f64.sqrt()).abs() <= 0.000000001);
+        assert!(across.len() <= 0.000000001);
+        assert!(along.dot(across).abs() <= 0.000000001);
+
+        // Every point on a bulging curve lies inside its box.
+        let arch: Bez2o<f64> = Bez2o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 3.0), Point2d::new(4.0, 2.0));
+        let (center, along, across) = arch.obb();
+        for p in arch.samples(200) {
+            let d = Vector2d::from(p - center);
+            assert!(d.project_onto(along).len() <= along.len() + 0.000000001);
+            assert!(d.project_onto(across).len() <= across.len() + 0.000000001);
+        }
+
+        let loop_curve: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(0.0, 2.0), Point2d::new(1.0, 2.0), Point2d::new(0.0, 0.0));
+        let (_, along, _) = loop_curve.obb();
+        assert!(along.normalize().dot(Vector2d::new(1.0, 2.0).normalize()).abs() >= 1.0 - 0.000000001);
+    }
+
+    #[test]
+    fn frames() {
+        let curve: Bez3o<f64> = Bez3o::new(Point2d::new(1.0, -1.0), Point2d::new(2.0, 3.0), Point2d::new(4.0, 0.0), Point2d::new(5.0, 2.0));
+        let frames: Vec<_> = curve.frames(11).collect();
+        assert_eq!(11, frames.len());
+        assert_eq!((0.0, curve.start), (frames[0].0, frames[0].1));
+        assert_eq!((1.0, curve.end), (frames[10].0, frames[10].1));
+
+        let points: Vec<_> = curve.samples(11).collect();
+        for (i, &(t, point, slope)) in frames.iter().enumerate() {
+            assert_eq!(points[i], point);
+            assert_eq!(curve.slope_unbounded(t), slope);
+        }
+
+        let reversed: Vec<_> = curve.frames(11).rev().map(|(t, _, _)| t).collect();
+        assert_eq!(frames.iter().rev().map(|&(t, _, _)| t).collect::<Vec<_>>(), reversed);
+        assert_eq!(0, curve.frames(0).count());
+    }
+
+    #[test]
+    fn simplify() {
+        let mut builder = ChainBuilder::new(Point2d::new(0.0f64, 0.0));
+        builder.line_to(Point2d::new(1.0, 0.5))
+               .cubic_to(Point2d::new(1.5, 0.75), Point2d::new(1.6, 0.8), Point2d::new(3.0, 1.5))
+               .line_to(Point2d::new(4.0, 2.0))
+               .line_to(Point2d::new(6.0, 3.0));
+        let line = builder.build();
+        let simplified = line.simplify(0.0001);
+        assert_eq!(1, simplified.iter().count());
+        assert_eq!(Point2d::new(0.0, 0.0), simplified.get(0).unwrap().start);
+        assert_eq!(Point2d::new(6.0, 3.0), simplified.get(0).unwrap().end);
+        for p in simplified.get(0).unwrap().samples(50) {
+            assert!((p.y - p.x * 0.5).abs() <= 0.0001);
+        }
+
+        // A sharp corner can't be approximated by one cubic, so both sides stay separate.
+        let mut builder = ChainBuilder::new(Point2d::new(0.0, 0.0));
+        builder.line_to(Point2d::new(2.0, 0.0))
+               .line_to(Point2d::new(4.0, 0.0))
+               .line_to(Point2d::new(4.0, 3.0));
+        let corner = builder.build().simplify(0.001);
+        let curves: Vec<_> = corner.iter().collect();
+        assert_eq!(2, curves.len());
+        assert_eq!(Point2d::new(4.0, 0.0), curves[0].end);
+        assert_eq!(Point2d::new(4.0, 3.0), curves[1].end);
+
+        let point: BezChain<f64, Bez3o<f64>, Vec<Point2d<f64>>> = BezChain::from_container(vec![Point2d::new(1.0, 1.0)]);
+        assert_eq!(&[Point2d::new(1.0, 1.0)], point.simplify(0.1).as_ref().as_slice());
+    }
+
+    #[test]
+    fn constant_speed_param() {
+        let s_curve: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(0.5, 2.0), Point2d::new(2.0, -2.0), Point2d::new(3.0, 0.0));
+        let points: Vec<_> = (0..=20)
+            .map(|i| s_curve.interp_unbounded(s_curve.constant_speed_param(i as f64 / 20.0, 0.0000001)))
+            .collect();
+        assert_eq!(s_curve.start, points[0]);
+        assert_eq!(s_curve.end, points[20]);
+
+        // Each step covers the same length of the curve, so the points along the curve are close to
+        // equidistant.
+        let dists: Vec<f64> = points.windows(2).map(|w| w[0].distance(w[1])).collect();
+        let mean = dists.iter().sum::<f64>() / dists.len() as f64;
+        for dist in &dists {
+            assert!((dist - mean).abs() <= mean * 0.02);
+        }
+
+        // The length of the curve up to the middle is half of its length.
+        let length = s_curve.arc_length(0.0000001);
+        let half = s_curve.constant_speed_param(0.5, 0.0000001);
+        assert!((s_curve.subsegment(0.0, half).unwrap().arc_length(0.0000001) - length / 2.0).abs() <= 0.000001);
+        assert_eq!(1.0, s_curve.constant_speed_param(1.5, 0.0000001));
+    }
+
+    #[test]
+    fn bernstein_coefficients() {
+        assert_eq!(vec![1, 4, 6, 4, 1], ::bernstein_coefficients(4));
+        assert_eq!(vec![1], ::bernstein_coefficients(0));
+        assert_eq!(vec![4, 12, 12, 4], bernstein_derivative_coefficients(4));
+        assert!(bernstein_derivative_coefficients(0).is_empty());
+
+        let highest = ::bernstein_coefficients(67);
+        assert_eq!(68, highest.len());
+        assert_eq!(14226520737620288370, highest[33]);
+    }
+
+    #[test]
+    fn speed_profile() {
+        let line: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(2.0, 4.0), Point2d::new(3.0, 6.0));
+        let speeds = line.speed_profile(25);
+        assert_eq!(25, speeds.len());
+        for speed in speeds {
+            assert!((speed - 45.0f64.sqrt()).abs() <= 0.000000001);
+        }
+
+        // Bunched up control points slow the curve down near them.
+        let uneven: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(0.1, 0.0), Point2d::new(0.2, 0.0), Point2d::new(3.0, 0.0));
+        let speeds = uneven.speed_profile(3);
+        assert!((speeds[0] - 0.3).abs() <= 0.000000001);
+        assert!(speeds[0] < speeds[1] && speeds[1] < speeds[2]);
+        assert!(uneven.speed_profile(0).is_empty());
+    }
+
+    #[test]
+    fn mirror() {
+        let curve: Bez3o<f64> = Bez3o::new(Point2d::new(1.0, 0.0), Point2d::new(2.0, 3.0), Point2d::new(4.0, -1.0), Point2d::new(5.0, 2.0));
+        let mirrored = curve.mirror(Point2d::new(0.0, 0.0), Vector2d::new(0.0, 2.0));
+        for t in (0..=10).map(|i| i as f64 / 10.0) {
+            let (p, m) = (curve.interp(t).unwrap(), mirrored.interp(t).unwrap());
+            assert!((p.x + m.x).abs() <= 0.000000001 && (p.y - m.y).abs() <= 0.000000001);
+        }
+        assert!((curve.signed_area() + mirrored.signed_area()).abs() <= 0.000000001);
+
+        // Mirroring across a diagonal line through a point off of the origin.
+        let line: Bez1o<f64> = Bez1o::new(Point2d::new(2.0, 1.0), Point2d::new(3.0, 1.0));
+        let mirrored = line.mirror(Point2d::new(1.0, 0.0), Vector2d::new(1.0, 1.0));
+        assert!(mirrored.start.approx_eq(Point2d::new(2.0, 1.0), 0.000000001));
+        assert!(mirrored.end.approx_eq(Point2d::new(2.0, 2.0), 0.000000001));
+    }
+
+    #[test]
+    fn closed() {
+        let mut builder = ChainBuilder::new(Point2d::new(0.0f64, 0.0));
+        builder.cubic_to(Point2d::new(1.0, 1.0), Point2d::new(2.0, 1.0), Point2d::new(3.0, 0.0))
+               .cubic_to(Point2d::new(4.0, -1.0), Point2d::new(4.0, -3.0), Point2d::new(2.0, -3.0));
+        let open = builder.build();
+        assert!(!open.is_closed(0.000000001));
+        assert!(open.is_closed(4.0));
+
+        let closed = open.closed();
+        assert_eq!(3, closed.segment_count());
+        assert!(closed.is_closed(0.0));
+        let join = closed.get(2).unwrap();
+        assert!(join.ctrl0.approx_eq(Point2d::new(4.0 / 3.0, -2.0), 0.000000001));
+        assert!(join.ctrl1.approx_eq(Point2d::new(2.0 / 3.0, -1.0), 0.000000001));
+
+        // The smooth closing curve leaves and enters tangent to the curves it joins.
+        let smooth = open.closed_smooth();
+        assert!(smooth.is_closed(0.0));
+        let join = smooth.get(2).unwrap();
+        assert!(join.slope(0.0).unwrap().normalize().approx_eq(open.get(1).unwrap().slope(1.0).unwrap().normalize(), 0.000000001));
+        assert!(join.slope(1.0).unwrap().normalize().approx_eq(open.get(0).unwrap().slope(0.0).unwrap().normalize(), 0.000000001));
+
+        // Already closed chains are left as they are.
+        assert_eq!(closed.as_ref(), closed.closed().as_ref());
+        assert_eq!(closed.as_ref(), closed.closed_smooth().as_ref());
+    }
+
+    #[test]
+    fn total_curvature() {
+        let line: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(2.5, 1.0), Point2d::new(2.75, 1.1), Point2d::new(5.0, 2.0));
+        assert!(line.total_curvature(0.000001).abs() <= 0.000000001);
+
+        let circle = BezChain::arc(Point2d::new(1.0, 2.0), 3.0f64, 0.0, 2.0 * ::std::f64::consts::PI);
+        let total: f64 = circle.iter().map(|c| c.total_curvature(0.0000001)).sum();
+        assert!((total - 2.0 * ::std::f64::consts::PI).abs() <= 0.000001);
+
+        // Turns in both directions count towards the total. The tangent turns from straight up to
+        // 45 degrees below horizontal and back.
+        let s_curve: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(0.0, 1.0), Point2d::new(1.0, -1.0), Point2d::new(1.0, 0.0));
+        assert!((s_curve.total_curvature(0.0000001) - 1.5 * ::std::f64::consts::PI).abs() <= 0.000001);
+    }
+
+    #[test]
+    fn match_orders() {
+        let quad: NBez<f64> = NBez::from_container(vec![Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(2.0, 0.0)]);
+        let cubic: NBez<f64> = NBez::from_container(vec![Point2d::new(0.0, 1.0), Point2d::new(1.0, 3.0), Point2d::new(2.0, -1.0), Point2d::new(3.0, 0.0)]);
+
+        let (a, b) = ::match_orders(&quad, &cubic);
+        assert_eq!((3, 3), (a.order(), b.order()));
+        assert!(curve_approx_eq(&a, &quad, 50, 0.000000001));
+        assert!(curve_approx_eq(&b, &cubic, 50, 0.000000001));
+
+        let (b, a) = ::match_orders(&cubic, &quad);
+        assert_eq!((3, 3), (b.order(), a.order()));
+        assert!(curve_approx_eq(&a, &quad, 50, 0.000000001));
+    }
+
+    #[test]
+    fn nbez_conversion() {
+        use std::convert::TryFrom;
+
+        let bez3o: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 3.0), Point2d::new(3.0, -2.0), Point2d::new(4.0, 1.0));
+        let nbez: NBez<f64> = NBez::from(bez3o);
+        assert_eq!(3, nbez.order());
+        for t in (0..=20).map(|i| i as f64 / 20.0) {
+            assert!(nbez.interp(t).unwrap().approx_eq(bez3o.interp(t).unwrap(), 0.000000001));
+        }
+
+        let back = Bez3o::try_from(nbez).unwrap();
+        assert_eq!(AsRef::<[Point2d<f64>]>::as_ref(&bez3o), AsRef::<[Point2d<f64>]>::as_ref(&back));
+
+        let scalar: NBez<f64, f64> = NBez::from(Bez2o::new(1.0, 2.0, 4.0));
+        assert_eq!(&[1.0, 2.0, 4.0], AsRef::<[f64]>::as_ref(&scalar));
+        match Bez4o::try_from(scalar) {
+            Err(BezError::OrderMismatch(4, 2)) => (),
+            other => panic!("unexpected conversion result {:?}", other.map(|c| c.start))
+        }
+    }
+
+    #[test]
+    fn from_polyline() {
+        let points = [Point2d::new(0.0f64, 0.0), Point2d::new(3.0, 1.0), Point2d::new(2.0, 5.0), Point2d::new(-1.0, 4.0)];
+        let chain = BezChain::from_polyline(&points);
+        assert_eq!(3, chain.segment_count());
+
+        for (curve, w) in chain.iter().zip(points.windows(2)) {
+            assert_eq!((w[0], w[1]), (curve.start, curve.end));
+            let dir = Vector2d::from(w[1] - w[0]);
+            for (i, p) in curve.samples(20).enumerate() {
+                let offset = Vector2d::from(p - w[0]);
+                assert!(dir.cross(offset).abs() <= 0.000000001);
+                // The curve moves along the segment at a constant speed.
+                assert!((offset.len() - dir.len() * i as f64 / 19.0).abs() <= 0.000000001);
+            }
+        }
+
+        assert_eq!(0, BezChain::<f64, Bez3o<f64>, _>::from_polyline(&[]).as_ref().len());
+        assert_eq!(0, BezChain::from_polyline(&[Point2d::new(1.0f64, 1.0)]).segment_count());
+    }
+
+    #[test]
+    fn intersect_line() {
+        let arch: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 4.0), Point2d::new(3.0, 4.0), Point2d::new(4.0, 0.0));
+        let ts = arch.intersect_line(Point2d::new(-5.0, 2.0), Vector2d::new(1.0, 0.0));
+        assert_eq!(2, ts.len());
+        assert!(ts[0] < 0.5 && ts[1] > 0.5);
+        assert!((ts[0] + ts[1] - 1.0).abs() <= 0.000000001);
+        for &t in &ts {
+            assert!((arch.interp(t).unwrap().y - 2.0).abs() <= 0.000000001);
+        }
+
+        // Only the crossing on the right is ahead of a ray starting in the middle of the arch.
+        assert_eq!(vec![ts[1]], arch.intersect_ray(Point2d::new(2.0, 2.0), Vector2d::new(3.0, 0.0)));
+        assert_eq!(vec![ts[0]], arch.intersect_segment(Point2d::new(-1.0, 2.0), Point2d::new(2.0, 2.0)));
+        assert!(arch.intersect_segment(Point2d::new(1.0, 2.0), Point2d::new(3.0, 2.0)).is_empty());
+        assert!(arch.intersect_line(Point2d::new(0.0, 5.0), Vector2d::new(1.0, 0.0)).is_empty());
+    }
+
+    #[test]
+    fn nearest_curve() {
+        let curves: Vec<Bez2o<f64>> = vec![
+            Bez2o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(2.0, 0.0)),
+            Bez2o::new(Point2d::new(5.0, 5.0), Point2d::new(6.0, 3.0), Point2d::new(7.0, 5.0)),
+            Bez2o::new(Point2d::new(-4.0, 4.0), Point2d::new(-3.0, 6.0), Point2d::new(-2.0, 4.0))
+        ];
+
+        let (index, t, dist) = ::nearest_curve(&curves, Point2d::new(6.0, 3.0), 0.0000001).unwrap();
+        assert_eq!(1, index);
+        assert!((t - 0.5).abs() <= 0.000001);
+        assert!((dist - 1.0).abs() <= 0.000001);
+
+        // The point is inside the first curve's control point box, but well away from the curve.
+        let (index, _, dist) = ::nearest_curve(&curves, Point2d::new(1.9, 2.0), 0.0000001).unwrap();
+        assert_eq!(0, index);
+        assert!((dist - 1.2438938941).abs() <= 0.000001);
+        let (index, _, _) = ::nearest_curve(&curves, Point2d::new(3.9, 2.6), 0.0000001).unwrap();
+        assert_eq!(1, index);
+
+        let (index, _, dist) = ::nearest_curve(&curves, Point2d::new(1.0, 1.0), 0.0000001).unwrap();
+        assert_eq!(0, index);
+        assert!(dist <= 0.000001);
+
+        assert_eq!(None, ::nearest_curve(&curves[..0], Point2d::new(0.0, 0.0), 0.0000001));
+    }
+
+    #[test]
+    fn classify() {
+        let point: Bez3o<f64> = Bez3o::new(Point2d::new(1.0, 1.0), Point2d::new(1.0, 1.0001), Point2d::new(0.9999, 1.0), Point2d::new(1.0, 1.0));
+        assert_eq!(CurveKind::Point, point.classify(0.001));
+        assert_eq!(CurveKind::Curved, point.classify(0.00000001));
+
+        // The control points double back along the line, which still makes the curve a line.
+        let line: Bez3o<f64, Point3d<f64>> = Bez3o::new(Point3d::new(0.0, 0.0, 0.0), Point3d::new(3.0, 3.0, 3.0), Point3d::new(-1.0, -1.0, -1.0), Point3d::new(1.0, 1.0, 1.0));
+        assert_eq!(CurveKind::Line, line.classify(0.000000001));
+
+        let curved: Bez2o<f64> = Bez2o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 0.1), Point2d::new(2.0, 0.0));
+        assert_eq!(CurveKind::Curved, curved.classify(0.01));
+        assert_eq!(CurveKind::Line, curved.classify(0.2));
+    }
+
+    #[test]
+    fn chord_and_polygon_length() {
+        let line: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(0.6, 0.8), Point2d::new(2.4, 3.2), Point2d::new(3.0, 4.0));
+        assert!((line.chord_length() - 5.0).abs() <= 0.000000001);
+        assert!((line.control_polygon_length() - 5.0).abs() <= 0.000000001);
+        assert!((line.arc_length(0.0000001) - 5.0).abs() <= 0.000001);
+
+        let arch: NBez<f64> = NBez::from_container(vec![Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(3.0, 2.0), Point2d::new(4.0, 0.0)]);
+        let length = arch.arc_length(0.0000001);
+        assert_eq!(4.0, arch.chord_length());
+        assert!((arch.control_polygon_length() - (2.0 + 2.0 * 5.0f64.sqrt())).abs() <= 0.000000001);
+        assert!(arch.chord_length() < length && length < arch.control_polygon_length());
+    }
+
+    #[test]
+    fn nbez_from_iter() {
+        let points = [Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(3.0, 2.0), Point2d::new(4.0, 0.0)];
+        let curve: NBez<f64> = points.iter().cloned().collect();
+        assert_eq!(3, curve.order());
+        assert_eq!(&points[..], AsRef::<[Point2d<f64>]>::as_ref(&curve));
+
+        let doubled = NBez::try_from_iter(points.iter().map(|p| *p * 2.0)).unwrap();
+        assert_eq!(Point2d::new(8.0, 0.0), AsRef::<[Point2d<f64>]>::as_ref(&doubled)[3]);
+        assert_eq!(Err(BezError::OrderTooHigh(99)), NBez::<f64, f64>::try_from_iter((0..100).map(|i| i as f64)).map(|c| c.order()));
+    }
+
+    #[test]
+    fn bez_error_display() {
+
+        let errors = [
+            BezError::ParseError("missing coordinate".to_owned()),
+            BezError::ParameterOutOfRange(1.5),
+            BezError::IndexOutOfRange(7),
+            BezError::OrderMismatch(3, 2),
+            BezError::OrderTooHigh(80)
+        ];
+        for error in errors.iter() {
+            assert!(!format!("{}", error).is_empty());
+        }
+        assert_eq!("expected a curve of order 3, found order 2", errors[3].to_string());
+
+        #[cfg(feature = "std")]
+        {
+            use std::error::Error;
+            let boxed: Box<dyn Error> = Box::new(BezError::IndexOutOfRange(7));
+            assert_eq!("index 7 is out of range", boxed.to_string());
+        }
+    }
+
+    #[test]
+    fn difference() {
+        let curve: NBez<f64> = NBez::from_container(vec![Point2d::new(0.0, 0.0), Point2d::new(1.0, 3.0), Point2d::new(3.0, -1.0), Point2d::new(4.0, 2.0)]);
+        let zero = ::difference(&curve, &curve).unwrap();
+        assert_eq!(3, zero.order());
+        assert!(AsRef::<[Point2d<f64>]>::as_ref(&zero).iter().all(|p| *p == Point2d::new(0.0, 0.0)));
+
+        // The difference between a curve and its elevation matches the difference between their points.
+        let quad: NBez<f64> = NBez::from_container(vec![Point2d::new(0.0, 1.0), Point2d::new(2.0, 2.0), Point2d::new(4.0, 1.0)]);
+        let diff = ::difference(&curve, &quad.elevate()).unwrap();
+        for t in (0..=10).map(|i| i as f64 / 10.0) {
+            let expected = curve.interp(t).unwrap() - quad.interp(t).unwrap();
+            assert!(diff.interp(t).unwrap().approx_eq(expected, 0.000000001));
+        }
+        assert!(::difference(&curve, &quad).is_none());
+    }
+
+    #[test]
+    fn bisect() {
+        let bez4o: Bez4o<f64> = Bez4o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 3.0), Point2d::new(2.0, -2.0), Point2d::new(3.0, 4.0), Point2d::new(4.0, 0.0));
+        let nbez: NBez<f64> = NBez::from(bez4o);
+
+        let (left, right) = bez4o.bisect();
+        let (nleft, nright) = nbez.bisect();
+        assert_eq!((4, 4), (left.order(), right.order()));
+        assert_eq!((4, 4), (nleft.order(), nright.order()));
+        assert_eq!(left.end, right.start);
+
+        for t in (0..=20).map(|i| i as f64 / 20.0) {
+            // Each half maps onto its half of the original curve's parameter range.
+            assert!(left.interp(t).unwrap().approx_eq(bez4o.interp(t * 0.5).unwrap(), 0.000000001));
+            assert!(right.interp(t).unwrap().approx_eq(bez4o.interp(0.5 + t * 0.5).unwrap(), 0.000000001));
+            assert!(nleft.interp(t).unwrap().approx_eq(left.interp(t).unwrap(), 0.000000001));
+            assert!(nright.interp(t).unwrap().approx_eq(right.interp(t).unwrap(), 0.000000001));
+        }
+    }
+
+    #[test]
+    fn stroke() {
+        use std::f64::consts::PI;
+
+        let line: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 0.0), Point2d::new(2.0, 0.0), Point2d::new(3.0, 0.0));
+        let butt = line.stroke(2.0, CapStyle::Butt, 0.0001);
+        assert!(butt.is_closed(0.0));
+        assert!((butt.signed_area() + 6.0).abs() <= 0.000000001);
+        for p in butt.iter().flat_map(|c| c.samples(10).collect::<Vec<_>>()) {
+            assert!(-0.000000001 <= p.x && p.x <= 3.000000001);
+            assert!((p.y.abs() - 1.0).abs() <= 0.000000001 || p.x.abs() <= 0.000000001 || (p.x - 3.0).abs() <= 0.000000001);
+        }
+
+        let square = line.stroke(2.0, CapStyle::Square, 0.0001);
+        assert!(square.is_closed(0.0));
+        assert!((square.signed_area() + 10.0).abs() <= 0.000000001);
+
+        let round = line.stroke(2.0, CapStyle::Round, 0.0001);
+        assert!(round.is_closed(0.0));
+        assert!((round.signed_area() + 6.0 + PI).abs() <= 0.01);
+        let (min, max) = round.iter().fold((Point2d::new(0.0f64, 0.0), Point2d::new(0.0f64, 0.0)), |(min, max), c| {
+            let (cmin, cmax) = c.bounding_box();
+            (min.min(cmin), max.max(cmax))
+        });
+        assert!(min.approx_eq(Point2d::new(-1.0, -1.0), 0.000000001));
+        assert!(max.approx_eq(Point2d::new(4.0, 1.0), 0.000000001));
+    }
+
+    #[test]
+    fn stroke_joins() {
+        let mut builder = ChainBuilder::new(Point2d::new(0.0f64, 0.0));
+        builder.line_to(Point2d::new(2.0, 0.0))
+               .line_to(Point2d::new(2.0, 2.0));
+        let corner = builder.build();
+        let near = |outline: &BezChain<f64, Bez3o<f64>, Vec<Point2d<f64>>>, p: Point2d<f64>| {
+            outline.as_ref().iter().any(|q| q.approx_eq(p, 0.000000001))
+        };
+
+        // The corner turns left, so the outside of it is on the right side of the chain.
+        let miter = corner.stroke(2.0, JoinStyle::Miter(4.0), CapStyle::Butt, 0.0001);
+        assert!(miter.is_closed(0.0));
+        assert!(near(&miter, Point2d::new(3.0, -1.0)));
+        assert!(near(&miter, Point2d::new(3.0, 2.0)) && near(&miter, Point2d::new(0.0, 1.0)));
+
+        // The miter is sqrt(2) times the width from the inside of the corner, which is over the limit.
+        let limited = corner.stroke(2.0, JoinStyle::Miter(1.2), CapStyle::Butt, 0.0001);
+        let bevel = corner.stroke(2.0, JoinStyle::Bevel, CapStyle::Butt, 0.0001);
+        assert_eq!(bevel.as_ref(), limited.as_ref());
+        assert!(!near(&bevel, Point2d::new(3.0, -1.0)));
+        assert!(near(&bevel, Point2d::new(2.0, -1.0)) && near(&bevel, Point2d::new(3.0, 0.0)));
+
+        let round = corner.stroke(2.0, JoinStyle::Round, CapStyle::Butt, 0.0001);
+        assert!(round.is_closed(0.0));
+        let corner_point = Point2d::new(2.0, 0.0) + Vector2d::new(0.5f64.sqrt(), -(0.5f64.sqrt()));
+        let curves: Vec<_> = round.iter().collect();
+        assert!(::nearest_curve(&curves, corner_point, 0.0000001).unwrap().2 <= 0.001);
+
+        // Joints where the chain stays smooth don't need any join.
+        let mut builder = ChainBuilder::new(Point2d::new(0.0f64, 0.0));
+        builder.line_to(Point2d::new(1.0, 0.0))
+               .line_to(Point2d::new(3.0, 0.0));
+        let straight = builder.build().stroke(2.0, JoinStyle::Miter(4.0), CapStyle::Butt, 0.0001);
+        assert_eq!(6, straight.segment_count());
+        assert!((straight.signed_area() + 6.0).abs() <= 0.000000001);
+    }
+
+    #[test]
+    fn fat_line() {
+        let curve: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 3.0), Point2d::new(3.0, -2.0), Point2d::new(4.0, 0.0));
+        let (dir, dmin, dmax) = curve.fat_line();
+        assert!(dir.approx_eq(Vector2d::new(1.0, 0.0), 0.000000001));
+        assert!((dmin + 2.0).abs() <= 0.000000001 && (dmax - 3.0).abs() <= 0.000000001);
+
+        let tilted: Bez3o<f64> = Bez3o::new(Point2d::new(1.0, 1.0), Point2d::new(0.0, 4.0), Point2d::new(5.0, 2.0), Point2d::new(4.0, 5.0));
+        let (dir, dmin, dmax) = tilted.fat_line();
+        assert!(dmin < 0.0 && dmax > 0.0);
+        for p in AsRef::<[Point2d<f64>]>::as_ref(&tilted) {
+            let d = dir.perp().dot(Vector2d::from(*p - tilted.start));
+            assert!(dmin - 0.000000001 <= d && d <= dmax + 0.000000001);
+        }
+        for p in tilted.samples(100) {
+            let d = dir.perp().dot(Vector2d::from(p - tilted.start));
+            assert!(dmin - 0.000000001 <= d && d <= dmax + 0.000000001);
+        }
+
+        let line: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(2.0, 2.0), Point2d::new(4.0, 4.0));
+        let (_, dmin, dmax) = line.fat_line();
+        assert!(dmin.abs() <= 0.000000001 && dmax.abs() <= 0.000000001);
+    }
+
+    #[test]
+    fn cusps() {
+        let cusp: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(0.0, 1.0), Point2d::new(1.0, 0.0));
+        assert_eq!(vec![0.5], cusp.cusps(0.000001));
+        assert!(cusp.speed(0.5).unwrap() <= 0.000000001);
+        assert_eq!(None, cusp.speed(1.5));
+
+        // A start handle that's on top of the start makes a cusp at the start.
+        let handle: Bez3o<f64, Point3d<f64>> = Bez3o::new(Point3d::new(1.0, 1.0, 1.0), Point3d::new(1.0, 1.0, 1.0), Point3d::new(2.0, 0.0, 3.0), Point3d::new(4.0, 1.0, 0.0));
+        assert_eq!(vec![0.0], handle.cusps(0.000001));
+
+        let smooth: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(3.0, 2.0), Point2d::new(4.0, 0.0));
+        assert!(smooth.cusps(0.000001).is_empty());
+        assert!((smooth.speed(0.0).unwrap() - 15.0f64.sqrt() * 3.0f64.sqrt()).abs() <= 0.000000001);
+    }
+
+    #[test]
+    fn to_cubic() {
+        let quad: Bez2o<f64> = Bez2o::new(Point2d::new(0.0, 0.0), Point2d::new(3.0, 6.0), Point2d::new(6.0, 0.0));
+        let cubic = quad.to_cubic();
+        let expected = [Point2d::new(0.0, 0.0), Point2d::new(2.0, 4.0), Point2d::new(4.0, 4.0), Point2d::new(6.0, 0.0)];
+        for (point, expected) in cubic.as_ref().iter().zip(expected.iter()) {
+            assert!((*point - *expected).x.abs() <= 0.000000001 && (*point - *expected).y.abs() <= 0.000000001);
+        }
+
+        for &t in &[0.0, 0.1, 0.25, 0.5, 0.8, 1.0] {
+            let diff = quad.interp(t).unwrap() - cubic.interp(t).unwrap();
+            assert!(diff.x.abs() <= 0.000000001 && diff.y.abs() <= 0.000000001);
+        }
+    }
+
+    #[test]
+    fn to_quadratics() {
+        let quad: Bez2o<f64> = Bez2o::new(Point2d::new(0.0, 0.0), Point2d::new(3.0, 6.0), Point2d::new(6.0, 0.0));
+        let quadratics = quad.to_cubic().to_quadratics(0.001);
+        assert_eq!(1, quadratics.len());
+        for (point, expected) in quadratics[0].as_ref().iter().zip(quad.as_ref().iter()) {
+            assert!((*point - *expected).x.abs() <= 0.000000001 && (*point - *expected).y.abs() <= 0.000000001);
+        }
+
+        // An S-shaped curve has an inflection, so it can't be a single quadratic.
+        let s_curve: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(1.0, 2.0), Point2d::new(2.0, -2.0), Point2d::new(3.0, 0.0));
+        let quadratics = s_curve.to_quadratics(0.001);
+        assert!(quadratics.len() >= 2);
+        assert_eq!(s_curve.start, quadratics[0].start);
+        assert_eq!(s_curve.end, quadratics[quadratics.len() - 1].end);
+        for pair in quadratics.windows(2) {
+            assert_eq!(pair[0].end, pair[1].start);
+        }
+        for quadratic in &quadratics {
+            for point in quadratic.samples(8) {
+                let (_, closest) = s_curve.project(point, 0.000001);
+                assert!(Vector2d::from(point - closest).len() <= 0.001);
+            }
+        }
+    }
+
+    #[test]
+    fn eval_all() {
+        let curve: Bez3o<f64, Point3d<f64>> = Bez3o::new(Point3d::new(0.0, 0.0, 1.0), Point3d::new(1.0, 3.0, 0.0), Point3d::new(2.0, -1.0, 4.0), Point3d::new(5.0, 2.0, 2.0));
+        let line: Bez1o<f64, f64> = Bez1o::new(0.0, 2.0);
+
+        for &t in &[0.0, 0.2, 0.5, 0.7, 1.0] {
+            let (point, slope, slope2) = curve.eval_all(t).unwrap();
+            assert!(Vector3d::from(point - curve.interp(t).unwrap()).len() <= 0.000000001);
+            assert!((slope - curve.slope(t).unwrap()).len() <= 0.000000001);
+            assert!((slope2 - curve.nth_derivative(2, t).unwrap()).len() <= 0.000000001);
+
+            assert_eq!((t * 2.0, 2.0, 0.0), line.eval_all(t).unwrap());
+        }
+        assert_eq!(None, curve.eval_all(1.5));
+    }
+
+    #[test]
+    fn point_min_max() {
+        let (a, b) = (Point2d::new(1.0, 4.0), Point2d::new(3.0, 2.0));
+        assert_eq!(Point2d::new(1.0, 2.0), a.min(b));
+        assert_eq!(Point2d::new(3.0, 4.0), a.max(b));
+        assert_eq!(Vector3d::new(-1.0, 0.0, 2.0), Vector3d::new(-1.0, 5.0, 2.0).min(Vector3d::new(0.0, 0.0, 3.0)));
+
+        // NaN components are ignored, like with `f64::min` and `f64::max`.
+        let nan = Point2d::new(f64::NAN, 1.0);
+        assert_eq!(Point2d::new(3.0, 1.0), nan.min(b));
+        assert_eq!(Point2d::new(3.0, 2.0), nan.max(b));
+
+        // Every point type gets the same through `PVOps`, which is what generic curve code uses.
+        let c: PointN<f64, 3> = PointN::new([1.0, f64::NAN, 5.0]);
+        let d: PointN<f64, 3> = PointN::new([2.0, 0.0, -1.0]);
+        assert_eq!(PointN::new([1.0, 0.0, -1.0]), PVOps::min(c, d));
+        assert_eq!(PointN::new([2.0, 0.0, 5.0]), PVOps::max(c, d));
+        assert_eq!(Point2d::new(1.0, 2.0), PVOps::min(a, b));
+    }
+
+    #[test]
+    fn custom_point() {
+        use std::ops::{Add, Sub, Mul, Div};
+        use std::slice;
+
+        // A single-axis point type defined outside of the crate, which the per-axis methods see
+        // through its slice methods.
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        struct Meters(f64);
+
+        impl Add for Meters {
+            type Output = Meters;
+            fn add(self, rhs: Meters) -> Meters {Meters(self.0 + rhs.0)}
+        }
+        impl Sub for Meters {
+            type Output = Meters;
+            fn sub(self, rhs: Meters) -> Meters {Meters(self.0 - rhs.0)}
+        }
+        impl Mul<f64> for Meters {
+            type Output = Meters;
+            fn mul(self, rhs: f64) -> Meters {Meters(self.0 * rhs)}
+        }
+        impl Div<f64> for Meters {
+            type Output = Meters;
+            fn div(self, rhs: f64) -> Meters {Meters(self.0 / rhs)}
+        }
+        impl Zero for Meters {
+            fn zero() -> Meters {Meters(0.0)}
+            fn is_zero(&self) -> bool {self.0 == 0.0}
+        }
+        impl PVOps<f64> for Meters {
+            fn as_slice(&self) -> &[f64] {slice::from_ref(&self.0)}
+            fn as_mut_slice(&mut self) -> &mut [f64] {slice::from_mut(&mut self.0)}
+        }
+        impl Point<f64> for Meters {
+            type Vector = Meters;
+        }
+        impl Vector<f64> for Meters {}
+
+        let curve = Bez2o::new(Meters(0.0), Meters(2.0), Meters(4.0));
+        assert_eq!(Meters(2.0), curve.interp(0.5).unwrap());
+        assert_eq!(Meters(4.0), curve.slope(0.5).unwrap());
+
+        let arch = Bez2o::new(Meters(0.0), Meters(4.0), Meters(1.0));
+        let (min, max) = arch.bounding_box();
+        assert_eq!(Meters(0.0), min);
+        assert!((max.0 - 16.0 / 7.0).abs() <= 0.000000001);
+    }
+
+    #[test]
+    fn slope2() {
+        // The second derivative of a quadratic is constant.
+        let bez2o = Bez2o::new(0.0, 1.0, -1.0);
+        for i in 0..11 {
+            assert!((bez2o.slope2(i as f64 / 10.0).unwrap() - -6.0).abs() <= 0.000000001);
+        }
+
+        // Compare the second derivative to the finite difference of the first.
+        let bez4o = Bez4o::new(0.0, 1.0, -1.0, 2.0, -2.0);
+        let h = 0.000001;
+        for i in 1..10 {
+            let t = i as f64 / 10.0;
+            let approx = (bez4o.slope(t + h).unwrap() - bez4o.slope(t - h).unwrap()) / (2.0 * h);
+            assert!((bez4o.slope2(t).unwrap() - approx).abs() <= 0.0001);
+        }
+
+        assert_eq!(Some(0.0), Bez1o::new(0.0, 1.0).slope2(0.5));
+        assert_eq!(None, bez4o.slope2(-0.5));
+    }
+
+    #[test]
+    fn nth_derivative() {
+        let bez3o = Bez3o::new(0.0, 1.0, -1.0, 2.0);
+
+        // The second derivative of a cubic is linear in `t`, and its third derivative is constant.
+        let d2_start = bez3o.nth_derivative(2, 0.0).unwrap();
+        let d2_end = bez3o.nth_derivative(2, 1.0).unwrap();
+        for i in 0..11 {
+            let t = i as f64 / 10.0;
+            let linear = d2_start + (d2_end - d2_start) * t;
+            assert!((bez3o.nth_derivative(2, t).unwrap() - linear).abs() <= 0.000000001);
+            assert!((bez3o.nth_derivative(3, t).unwrap() - (d2_end - d2_start)).abs() <= 0.000000001);
+            assert_eq!(Some(0.0), bez3o.nth_derivative(4, t));
+
+            assert!((bez3o.nth_derivative(0, t).unwrap() - bez3o.interp(t).unwrap()).abs() <= 0.000000001);
+            assert!((bez3o.nth_derivative(1, t).unwrap() - bez3o.slope(t).unwrap()).abs() <= 0.000000001);
+        }
+
+        let nbez_poly: NBez<f64, f64> = NBez::from_container(vec![0.0, 1.0, -1.0, 2.0]);
+        for i in 0..11 {
+            let t = i as f64 / 10.0;
+            assert!((nbez_poly.nth_derivative(2, t).unwrap() - bez3o.nth_derivative(2, t).unwrap()).abs() <= 0.000000001);
+        }
+    }
+
+    #[test]
+    fn curvature() {
+        // Cubic approximation of a quarter of the unit circle, running counter-clockwise.
+        let k = 0.5522847498;
+        let arc = Bez3o::new(
+            Point2d::new(1.0, 0.0),
+            Point2d::new(1.0, k),
+            Point2d::new(k, 1.0),
+            Point2d::new(0.0, 1.0)
+        );
+        for i in 0..11 {
+            assert!((arc.curvature(i as f64 / 10.0).unwrap() - 1.0).abs() <= 0.025);
+        }
+
+        let clockwise = Bez3o::new(arc.end, arc.ctrl1, arc.ctrl0, arc.start);
+        assert!((clockwise.curvature(0.5).unwrap() + 1.0).abs() <= 0.02);
+
+        // A curve with a cusp at `t = 0` produces zero rather than NaN.
+        let cusp = Bez3o::new(
+            Point2d::new(0.0, 0.0),
+            Point2d::new(0.0, 0.0),
+            Point2d::new(1.0, 1.0),
+            Point2d::new(2.0, 0.0)
+        );
+        assert_eq!(Some(0.0), cusp.curvature(0.0));
+    }
+
+    #[test]
+    fn project() {
+        let line = Bez3o::new(
+            Point2d::new(0.0, 0.0),
+            Point2d::new(1.0, 0.0),
+            Point2d::new(2.0, 0.0),
+            Point2d::new(3.0, 0.0)
+        );
+
+        for i in 0..11 {
+            let expected = i as f64 / 10.0;
+            let (t, p) = line.project(Point2d::new(expected * 3.0, 2.0), 0.0000001);
+            assert!((t - expected).abs() <= 0.000001);
+            assert!((p.x - expected * 3.0).abs() <= 0.000001 && p.y.abs() <= 0.000001);
+        }
+
+        let (t, _) = line.project(Point2d::new(-1.0, 1.0), 0.0000001);
+        assert_eq!(0.0, t);
+        let (t, _) = line.project(Point2d::new(5.0, -1.0), 0.0000001);
+        assert_eq!(1.0, t);
+
+        // A curve that folds back on itself, where the closest point is near the fold.
+        let fold: NBez<f64> = NBez::from_container(vec![
+            Point2d::new(0.0, 0.0),
+            Point2d::new(4.0, 0.0),
+            Point2d::new(4.0, 1.0),
+            Point2d::new(0.0, 1.0)
+        ]);
+        let (t, p) = fold.project(Point2d::new(5.0, 0.5), 0.0000001);
+        assert!((t - 0.5).abs() <= 0.000001);
+        assert!((p.x - 3.0).abs() <= 0.000001 && (p.y - 0.5).abs() <= 0.000001);
+    }
+
+    #[test]
+    fn flatten() {
+        let almost_straight = Bez3o::new(
+            Point2d::new(0.0, 0.0),
+            Point2d::new(1.0, 0.001),
+            Point2d::new(2.0, -0.001),
+            Point2d::new(3.0, 0.0)
+        );
+        assert_eq!(vec![almost_straight.start, almost_straight.end], almost_straight.flatten(0.01));
+
+        let semicircle: NBez<f64> = NBez::from_container(vec![
+            Point2d::new(-1.0, 0.0),
+            Point2d::new(-1.0, 1.333),
+            Point2d::new(1.0, 1.333),
+            Point2d::new(1.0, 0.0)
+        ]);
+        let polyline = semicircle.flatten(0.01);
+        assert!(polyline.len() > 8 && polyline.len() <= 32);
+        assert_eq!(Point2d::new(-1.0, 0.0), polyline[0]);
+        assert_eq!(Point2d::new(1.0, 0.0), polyline[polyline.len() - 1]);
+
+        for p in polyline {
+            let (_, closest) = semicircle.project(p, 0.0000001);
+            assert!(Vector2d::from(closest - p).len() <= 0.000001);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let cubic: Bez3o<f64> = Bez3o::new(
+            Point2d::new(0.0, 0.0),
+            Point2d::new(1.0, 2.0),
+            Point2d::new(3.0, -1.0),
+            Point2d::new(4.0, 1.0)
+        );
+        let json = serde_json::to_string(&cubic).unwrap();
+        let cubic_de: Bez3o<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(cubic.as_ref(), cubic_de.as_ref());
+
+        let vector = Vector3d::new(1.0, -2.0, 3.5);
+        assert_eq!(vector, serde_json::from_str(&serde_json::to_string(&vector).unwrap()).unwrap());
+
+        let nbez_poly: NBez<f64> = NBez::from_container(cubic.as_ref().to_vec());
+        let json = serde_json::to_string(&nbez_poly).unwrap();
+        assert_eq!("[{\"x\":0.0,\"y\":0.0},{\"x\":1.0,\"y\":2.0},{\"x\":3.0,\"y\":-1.0},{\"x\":4.0,\"y\":1.0}]", json);
+
+        let nbez_de: NBez<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(nbez_poly.as_ref() as &[Point2d<f64>], nbez_de.as_ref() as &[Point2d<f64>]);
+        test_poly_eq(&Bez1o::new(0.0, 1.0), &serde_json::from_str::<NBez<f64, f64>>("[0.0, 1.0]").unwrap());
+
+        assert!(serde_json::from_str::<NBez<f64, f64>>(&serde_json::to_string(&vec![0.0; 69]).unwrap()).is_err());
+    }
+
+    #[test]
+    fn svg_path_export() {
+        let chain: BezChain<f64, Bez3o<f64>, Vec<Point2d<f64>>> = BezChain::from_container(vec![
+            Point2d::new(0.0, 0.0),
+            Point2d::new(1.0, 2.0),
+            Point2d::new(3.0, 2.0),
+            Point2d::new(4.0, 0.0),
+            Point2d::new(5.0, -2.0),
+            Point2d::new(7.5, -2.0),
+            Point2d::new(8.0, 0.25)
+        ]);
+
+        assert_eq!("M0 0 C1 2 3 2 4 0 C5 -2 7.5 -2 8 0.25", chain.to_svg_path());
+        assert_eq!("M0.0 0.0 C1.0 2.0 3.0 2.0 4.0 0.0 C5.0 -2.0 7.5 -2.0 8.0 0.2", chain.to_svg_path_prec(1));
+
+        let empty: BezChain<f64, Bez3o<f64>, Vec<Point2d<f64>>> = BezChain::from_container(vec![]);
+        assert_eq!("", empty.to_svg_path());
+    }
+
+    #[test]
+    fn vector_products() {
+        let a = Vector2d::new(2.0, 1.0);
+        assert_eq!(0.0, a.dot(a.perp()));
+        assert_eq!(5.0, a.dot(a));
+        assert_eq!(5.0, a.cross(a.perp()));
+        assert_eq!(-5.0, a.perp().cross(a));
+
+        assert_eq!(0.0, Vector4d::new(1.0, 0.0, 1.0, 0.0).dot(Vector4d::new(0.0, 3.0, 0.0, 2.0)));
+
+        let x = Vector3d::new(1.0, 0.0, 0.0);
+        let y = Vector3d::new(0.0, 1.0, 0.0);
+        assert_eq!(Vector3d::new(0.0, 0.0, 1.0), x.cross(y));
+        assert_eq!(Vector3d::new(0.0, 0.0, -1.0), y.cross(x));
+        assert_eq!(0.0, x.cross(y).dot(x));
+    }
+
+    #[test]
+    fn point_distance() {
+        let a = Point2d::new(1.0, 1.0);
+        let b = Point2d::new(4.0, 5.0);
+        assert_eq!(5.0, a.distance(b));
+        assert_eq!(5.0, b.distance(a));
+        assert_eq!(25.0, a.distance_squared(b));
+        assert_eq!(0.0, a.distance(a));
+    }
+
+    #[test]
+    fn point_approx_eq() {
+        let a = Point2d::new(0.5, 2.0);
+        let b = Point2d::new(0.5 + 1e-15, 2.0);
+        assert!(a != b);
+        assert!(a.approx_eq(b, 1e-9));
+        assert!(!a.approx_eq(Point2d::new(0.5, 2.1), 1e-9));
+        assert!(Vector3d::new(1.0, 2.0, 3.0).approx_eq(Vector3d::new(1.0, 2.0, 3.0 - 1e-12), 1e-9));
+    }
+
+    #[test]
+    fn svg_path_import() {
+        let path = "M0 0 C1 2 3 2 4 0 C5 -2 7.5 -2 8 0.25";
+        let chain = BezChain::<f64, Bez3o<f64>, Vec<Point2d<f64>>>::from_svg_path(path).unwrap();
+        assert_eq!(path, chain.to_svg_path());
+
+        // Relative commands, implicit repetition, and lines.
+        let chain = BezChain::<f64, Bez3o<f64>, Vec<Point2d<f64>>>::from_svg_path("m1,1 3,0 c1-1,2-1,3,0 z").unwrap();
+        assert_eq!("M1 1 C2 1 3 1 4 1 C5 0 6 0 7 1 C5 1 3 1 1 1", chain.to_svg_path());
+
+        assert!(BezChain::<f64, Bez3o<f64>, Vec<Point2d<f64>>>::from_svg_path("L1 1").is_err());
+        assert!(BezChain::<f64, Bez3o<f64>, Vec<Point2d<f64>>>::from_svg_path("M0 0 C1 2 3").is_err());
+        assert!(BezChain::<f64, Bez3o<f64>, Vec<Point2d<f64>>>::from_svg_path("M0 0 Q1 1 2 2").is_err());
+    }
+
     fn test_interp_iter<B>(curve: &B)
             where B: BezCurve<f64, Point = f64> {
 
@@ -526,4 +4034,44 @@ mod tests {
         let bez6o = Bez6o::new(0.0, 1.0, -1.0, 2.0, -2.0, 3.0, -3.0);
         test_interp_iter(&bez6o);
     }
+
+    #[test]
+    fn samples() {
+        let bez3o = Bez3o::new(0.0, 1.0, -1.0, 2.0);
+
+        let samples: Vec<f64> = bez3o.samples(5).collect();
+        assert_eq!(5, samples.len());
+        for (i, sample) in samples.iter().enumerate() {
+            assert_eq!(bez3o.interp(i as f64 / 4.0).unwrap(), *sample);
+        }
+
+        assert_eq!(vec![0.0], bez3o.samples(1).collect::<Vec<_>>());
+        assert_eq!(0, bez3o.samples(0).len());
+        assert_eq!(None, bez3o.samples(0).next_back());
+        assert_eq!(3, bez3o.samples(3).rev().count());
+    }
+
+    #[test]
+    fn samples_by_arclength() {
+        let s_curve: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(0.5, 1.0), Point2d::new(2.0, -1.0), Point2d::new(3.0, 0.0));
+
+        let samples = s_curve.samples_by_arclength(30, 0.0000001);
+        assert_eq!(30, samples.len());
+        assert_eq!(Point2d::new(0.0, 0.0), samples[0]);
+        assert!((samples[29].x - 3.0).abs() <= 0.000000001 && samples[29].y.abs() <= 0.000000001);
+
+        let dists: Vec<f64> = samples.windows(2).map(|w| (w[1].x - w[0].x).hypot(w[1].y - w[0].y)).collect();
+        let mean = dists.iter().sum::<f64>() / dists.len() as f64;
+        for dist in &dists {
+            assert!((dist - mean).abs() <= mean * 0.01);
+        }
+        assert!((s_curve.arc_length(0.0000001) - mean * 29.0).abs() <= 0.01);
+
+        // A straight line with uneven speed.
+        let line: Bez3o<f64> = Bez3o::new(Point2d::new(0.0, 0.0), Point2d::new(2.5, 0.0), Point2d::new(2.9, 0.0), Point2d::new(3.0, 0.0));
+        assert!((line.arc_length(0.0000001) - 3.0).abs() <= 0.000000001);
+        for (i, sample) in line.samples_by_arclength(4, 0.0000001).iter().enumerate() {
+            assert!((sample.x - i as f64).abs() <= 0.000001 && sample.y.abs() <= 0.000000001);
+        }
+    }
 }
\ No newline at end of file
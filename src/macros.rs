@@ -1,7 +1,7 @@
 // Vector/Point macros
 macro_rules! n_pointvector {
     (ops $lhs:ident; $rhs:ident {$($field:ident),*}) => {
-        impl<F: $crate::Float> ::std::ops::Add<$rhs<F>> for $lhs<F> {
+        impl<F: $crate::Float> ::core::ops::Add<$rhs<F>> for $lhs<F> {
             type Output = $lhs<F>;
 
             fn add(self, rhs: $rhs<F>) -> $lhs<F> {
@@ -11,7 +11,7 @@ macro_rules! n_pointvector {
             }
         }
 
-        impl<F: $crate::Float> ::std::ops::Sub<$rhs<F>> for $lhs<F> {
+        impl<F: $crate::Float> ::core::ops::Sub<$rhs<F>> for $lhs<F> {
             type Output = $lhs<F>;
 
             fn sub(self, rhs: $rhs<F>) -> $lhs<F> {
@@ -21,7 +21,7 @@ macro_rules! n_pointvector {
             }
         }
 
-        impl<F: $crate::Float> std::ops::Mul<$rhs<F>> for $lhs<F> {
+        impl<F: $crate::Float> core::ops::Mul<$rhs<F>> for $lhs<F> {
             type Output = $lhs<F>;
 
             fn mul(self, rhs: $rhs<F>) -> $lhs<F> {
@@ -31,7 +31,7 @@ macro_rules! n_pointvector {
             }
         }
 
-        impl<F: $crate::Float> std::ops::Div<$rhs<F>> for $lhs<F> {
+        impl<F: $crate::Float> core::ops::Div<$rhs<F>> for $lhs<F> {
             type Output = $lhs<F>;
 
             fn div(self, rhs: $rhs<F>) -> $lhs<F> {
@@ -43,7 +43,7 @@ macro_rules! n_pointvector {
     };
 
     (float ops $name:ident {$($field:ident),+}) => {
-        impl<F: $crate::Float> ::std::ops::Mul<F> for $name<F> {
+        impl<F: $crate::Float> ::core::ops::Mul<F> for $name<F> {
             type Output = $name<F>;
 
             fn mul(self, rhs: F) -> $name<F> {
@@ -53,7 +53,7 @@ macro_rules! n_pointvector {
             }
         }
 
-        impl<F: $crate::Float> ::std::ops::Div<F> for $name<F> {
+        impl<F: $crate::Float> ::core::ops::Div<F> for $name<F> {
             type Output = $name<F>;
 
             fn div(self, rhs: F) -> $name<F> {
@@ -63,7 +63,7 @@ macro_rules! n_pointvector {
             }
         }
 
-        impl<F: $crate::Float> ::std::ops::Neg for $name<F> {
+        impl<F: $crate::Float> ::core::ops::Neg for $name<F> {
             type Output = $name<F>;
 
             fn neg(self) -> $name<F> {
@@ -75,8 +75,11 @@ macro_rules! n_pointvector {
     };
 
     (struct $doc:expr, $dims:expr; $name:ident {$($field:ident: $f_ty:ident),+} $sibling:ident) => {
-        #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
         #[doc=$doc]
+        // `AsRef<[F]>` and `AsMut<[F]>` rely on the fields being laid out in order, like an array.
+        #[repr(C)]
         pub struct $name<F: $crate::Float> {
             $(pub $field: F),+
         }
@@ -89,9 +92,40 @@ macro_rules! n_pointvector {
                     $($field: $field),+
                 }
             }
+
+            /// Returns true if every component of `self` is within `epsilon` of the corresponding
+            /// component of `other`.
+            pub fn approx_eq(self, other: $name<F>, epsilon: F) -> bool {
+                $((self.$field - other.$field).abs() <= epsilon)&&+
+            }
+
+            /// Returns the componentwise minimum of `self` and `other`. Like `Float::min`, a NaN
+            /// component is ignored in favor of the other one.
+            pub fn min(self, other: $name<F>) -> $name<F> {
+                $name {
+                    $($field: self.$field.min(other.$field)),+
+                }
+            }
+
+            /// Returns the componentwise maximum of `self` and `other`. Like `Float::max`, a NaN
+            /// component is ignored in favor of the other one.
+            pub fn max(self, other: $name<F>) -> $name<F> {
+                $name {
+                    $($field: self.$field.max(other.$field)),+
+                }
+            }
         }
 
-        impl<F: $crate::Float> ::std::convert::From<[F; $dims]> for $name<F> {
+        /// The default is the origin, with every component being zero.
+        impl<F: $crate::Float> ::core::default::Default for $name<F> {
+            fn default() -> $name<F> {
+                $name {
+                    $($field: F::from_f32(0.0).unwrap()),+
+                }
+            }
+        }
+
+        impl<F: $crate::Float> ::core::convert::From<[F; $dims]> for $name<F> {
             fn from(array: [F; $dims]) -> $name<F> {
                 let mut index = -1;
                 $name{$($field: {
@@ -101,19 +135,19 @@ macro_rules! n_pointvector {
             }
         }
 
-        impl<F: $crate::Float> ::std::convert::Into<[F; $dims]> for $name<F> {
+        impl<F: $crate::Float> ::core::convert::Into<[F; $dims]> for $name<F> {
             fn into(self) -> [F; $dims] {
                 [$(self.$field),*]
             }
         }
 
-        impl<F: $crate::Float> ::std::convert::Into<($($f_ty),*)> for $name<F> {
-            fn into(self) -> ($($f_ty),*) {
-                ($(self.$field),*)
+        impl<F: $crate::Float> ::core::convert::Into<($($f_ty,)*)> for $name<F> {
+            fn into(self) -> ($($f_ty,)*) {
+                ($(self.$field,)*)
             }
         }
 
-        impl<F: $crate::Float> ::std::convert::From<$sibling<F>> for $name<F> {
+        impl<F: $crate::Float> ::core::convert::From<$sibling<F>> for $name<F> {
             fn from(sib: $sibling<F>) -> $name<F> {
                 $name {
                     $($field: sib.$field),*
@@ -121,24 +155,80 @@ macro_rules! n_pointvector {
             }
         }
 
-        impl<F: $crate::Float> ::std::convert::AsRef<[F]> for $name<F> {
+        impl<F: $crate::Float> ::core::convert::AsRef<[F]> for $name<F> {
             fn as_ref(&self) -> &[F] {
-                use std::slice;
+                use core::slice;
                 unsafe {
                     slice::from_raw_parts(self as *const $name<F> as *const F, $dims)
                 }
             }
         }
 
-        impl<F: $crate::Float> ::std::convert::AsMut<[F]> for $name<F> {
+        impl<F: $crate::Float> ::core::convert::AsMut<[F]> for $name<F> {
             fn as_mut(&mut self) -> &mut [F] {
-                use std::slice;
+                use core::slice;
                 unsafe {
                     slice::from_raw_parts_mut(self as *mut $name<F> as *mut F, $dims)
                 }
             }
         }
 
+        impl<F: $crate::Float> $name<F> {
+            /// Get an iterator over the components of this struct, in the same order as its fields.
+            pub fn components(&self) -> ::core::iter::Cloned<::core::slice::Iter<'_, F>> {
+                AsRef::<[F]>::as_ref(self).iter().cloned()
+            }
+        }
+
+        /// Formats the components in parentheses, like `(1, 2)`. Formatting flags such as precision get
+        /// applied to each component.
+        impl<F: $crate::Float + ::core::fmt::Display> ::core::fmt::Display for $name<F> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                f.write_str("(")?;
+                for (i, c) in self.components().enumerate() {
+                    if i != 0 {
+                        f.write_str(", ")?;
+                    }
+                    ::core::fmt::Display::fmt(&c, f)?;
+                }
+                f.write_str(")")
+            }
+        }
+
+        impl<F: $crate::Float> ::core::cmp::PartialEq for $crate::HashablePoint<$name<F>> {
+            fn eq(&self, other: &$crate::HashablePoint<$name<F>>) -> bool {
+                $($crate::float_key(self.0.$field) == $crate::float_key(other.0.$field))&&+
+            }
+        }
+
+        impl<F: $crate::Float> ::core::cmp::Eq for $crate::HashablePoint<$name<F>> {}
+
+        impl<F: $crate::Float> ::core::hash::Hash for $crate::HashablePoint<$name<F>> {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                $($crate::float_key(self.0.$field).hash(state);)+
+            }
+        }
+
+        impl<F: $crate::Float> ::core::ops::Index<usize> for $name<F> {
+            type Output = F;
+
+            fn index(&self, index: usize) -> &F {
+                if index >= $dims {
+                    panic!("Index {} is out of range for {} with {} dimensions", index, stringify!($name), $dims);
+                }
+                &AsRef::<[F]>::as_ref(self)[index]
+            }
+        }
+
+        impl<F: $crate::Float> ::core::ops::IndexMut<usize> for $name<F> {
+            fn index_mut(&mut self, index: usize) -> &mut F {
+                if index >= $dims {
+                    panic!("Index {} is out of range for {} with {} dimensions", index, stringify!($name), $dims);
+                }
+                &mut AsMut::<[F]>::as_mut(self)[index]
+            }
+        }
+
         impl<F: $crate::Float> num_traits::identities::Zero for $name<F> {
             fn zero() -> $name<F> {
                 $name {
@@ -178,14 +268,60 @@ macro_rules! n_pointvector {
             pub fn normalize(self) -> $v_name<F> {
                 self / self.len()
             }
+
+            /// Get the dot product of `self` and `rhs`.
+            pub fn dot(self, rhs: $v_name<F>) -> F {
+                $(self.$field * rhs.$field +)+ F::from_f32(0.0).unwrap()
+            }
+
+            /// Get the projection of `self` onto `other`, which is the part of `self` that points in
+            /// the same direction as `other`. If `other` has a length of zero, returns a zero vector.
+            pub fn project_onto(self, other: $v_name<F>) -> $v_name<F> {
+                let other_len_sq = other.dot(other);
+                if other_len_sq == F::zero() {
+                    other
+                } else {
+                    other * (self.dot(other) / other_len_sq)
+                }
+            }
         }
 
-        impl<F: Float> PVOps<F> for $p_name<F> {}
+        impl<F: $crate::Float> $p_name<F> {
+            /// Get the euclidean distance between `self` and `other`.
+            pub fn distance(self, other: $p_name<F>) -> F {
+                $v_name::from(self - other).len()
+            }
+
+            /// Get the square of the euclidean distance between `self` and `other`. This avoids taking a
+            /// square root, so it's cheaper than `distance` when only comparing distances.
+            pub fn distance_squared(self, other: $p_name<F>) -> F {
+                let diff = $v_name::from(self - other);
+                diff.dot(diff)
+            }
+        }
+
+        impl<F: Float> PVOps<F> for $p_name<F> {
+            fn as_slice(&self) -> &[F] {
+                self.as_ref()
+            }
+
+            fn as_mut_slice(&mut self) -> &mut [F] {
+                self.as_mut()
+            }
+        }
         impl<F: Float> Point<F> for $p_name<F> {
             type Vector = $v_name<F>;
         }
 
-        impl<F: Float> PVOps<F> for $v_name<F> {}
+        impl<F: Float> PVOps<F> for $v_name<F> {
+            fn as_slice(&self) -> &[F] {
+                self.as_ref()
+            }
+
+            fn as_mut_slice(&mut self) -> &mut [F] {
+                self.as_mut()
+            }
+        }
         impl<F: Float> Vector<F> for $v_name<F> {}
     }
 }
@@ -230,12 +366,16 @@ macro_rules! n_bezier {
         $end:ident;
     } elevated $elevated:ident<$($est:ty),+>) => {
         #[derive(Debug, Clone, Copy)]
+        #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
         #[doc=$doc]
+        // `AsRef<[P]>` and `AsMut<[P]>` rely on the points being laid out in order, like an array.
+        #[repr(C)]
         pub struct $name<F, P = $crate::Point2d<F>>
                 where F: $crate::Float,
                       P: $crate::Point<F> {
             $(pub $field: P),+,
-            __marker: std::marker::PhantomData<F>
+            #[cfg_attr(feature = "serde", serde(skip))]
+            __marker: core::marker::PhantomData<F>
         }
 
         impl<F, P> $name<F, P>
@@ -246,8 +386,27 @@ macro_rules! n_bezier {
             pub fn new($($field: P),+) -> $name<F, P> {
                 $name {
                     $($field: $field),+,
-                    __marker: std::marker::PhantomData
+                    __marker: core::marker::PhantomData
+                }
+            }
+
+            /// Convert the curve to the power basis, getting the coefficients of the polynomial
+            /// `a[0] + a[1] * t + a[2] * t^2 + ...` which traces the same curve. Evaluating those
+            /// coefficients with `eval_power_basis` takes fewer operations than `interp_unbounded`, so
+            /// this is worth doing when sampling the same curve many times.
+            pub fn to_power_basis(&self) -> [P; $order + 1] {
+                let points: &[P] = self.as_ref();
+                let mut coeffs = [P::zero(); $order + 1];
+
+                for (k, coeff) in coeffs.iter_mut().enumerate() {
+                    let mut sum = P::zero();
+                    for (i, point) in points[..=k].iter().enumerate() {
+                        let term = *point * $crate::binomial(k, i);
+                        sum = if (k - i) % 2 == 0 {sum + term} else {sum - term};
+                    }
+                    *coeff = sum * $crate::binomial($order, k);
                 }
+                coeffs
             }
         }
 
@@ -255,7 +414,7 @@ macro_rules! n_bezier {
                 where P: $crate::Point<F>,
                       F: $crate::Float {
             type Point = P;
-            type Elevated = $elevated<$($est),+>;
+            type Split = $name<F, P>;
 
             fn from_slice(slice: &[P]) -> Option<$name<F, P>> {
                 use $crate::OrderStatic;
@@ -267,7 +426,7 @@ macro_rules! n_bezier {
                         index += 1;
                         slice[index as usize]
                     }),+,
-                    __marker: std::marker::PhantomData})
+                    __marker: core::marker::PhantomData})
                 }
             }
 
@@ -282,7 +441,7 @@ macro_rules! n_bezier {
                         self.$field *
                         t1.powi(factor) * 
                         t.powi(COUNT-factor) *  
-                        F::from_i32($weight).unwrap();
+                        F::from_u64($weight).unwrap();
                 )+
                 $($field +)+ P::zero()
             }
@@ -298,24 +457,11 @@ macro_rules! n_bezier {
                         (self.$right - self.$left) *
                         t1.powi(factor) *
                         t.powi(COUNT-factor) *
-                        F::from_i32($dweight * (COUNT + 1)).unwrap();
+                        F::from_u64($dweight * (COUNT as u64 + 1)).unwrap();
                 )+
                 ($($right +)+ P::zero()).into()
             }
 
-            fn elevate(&self) -> $elevated<$($est),+> {
-                let mut factor = 0.0;
-                let order = F::from_usize(self.order() + 1).unwrap();
-                $elevated::from([self.$start, 
-                    $({
-                        factor += 1.0;
-                        let factor = F::from_f32(factor).unwrap();
-                        (self.$left * factor +
-                        self.$right * (order - factor)) / order
-                    },)+
-                    self.$end])
-            }
-
             fn split_unbounded(&self, t: F) -> ($name<F, P>, $name<F, P>) {
                 use $crate::lerp;
 
@@ -404,6 +550,26 @@ macro_rules! n_bezier {
             }
         }
 
+        #[cfg(feature = "alloc")]
+        impl<F, P> $crate::BezElevate<F> for $name<F, P>
+                where P: $crate::Point<F>,
+                      F: $crate::Float {
+            type Elevated = $elevated<$($est),+>;
+
+            fn elevate(&self) -> $elevated<$($est),+> {
+                let mut factor = 0.0;
+                let order = F::from_usize(self.order() + 1).unwrap();
+                $elevated::from([self.$start, 
+                    $({
+                        factor += 1.0;
+                        let factor = F::from_f32(factor).unwrap();
+                        (self.$left * factor +
+                        self.$right * (order - factor)) / order
+                    },)+
+                    self.$end])
+            }
+        }
+
         impl<F, P> $crate::OrderStatic for $name<F, P> 
                 where F: $crate::Float,
                       P: $crate::Point<F> {
@@ -413,7 +579,7 @@ macro_rules! n_bezier {
             }
         }
 
-        impl<F, P> ::std::convert::From<[P; $order + 1]> for $name<F, P> 
+        impl<F, P> ::core::convert::From<[P; $order + 1]> for $name<F, P> 
                 where F: $crate::Float,
                       P: $crate::Point<F> {
             fn from(array: [P; $order + 1]) -> $name<F, P> {
@@ -422,22 +588,50 @@ macro_rules! n_bezier {
             }
         }
 
-        impl<F, P> ::std::convert::AsRef<[P]> for $name<F, P> 
+        #[cfg(feature = "alloc")]
+        impl<F, P> ::core::convert::From<$name<F, P>> for $crate::NBez<F, P, ::alloc::vec::Vec<P>>
+                where F: $crate::Float,
+                      P: $crate::Point<F> {
+            fn from(curve: $name<F, P>) -> $crate::NBez<F, P, ::alloc::vec::Vec<P>> {
+                $crate::NBez::from_container(curve.as_ref().to_vec())
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<F, P, C> ::core::convert::TryFrom<$crate::NBez<F, P, C>> for $name<F, P>
+                where F: $crate::Float,
+                      P: $crate::Point<F>,
+                      C: AsRef<[P]> + AsMut<[P]> {
+            type Error = $crate::BezError;
+
+            /// Copy the points of `curve` into a curve of this type, failing if `curve`'s order isn't
+            /// this type's order.
+            fn try_from(curve: $crate::NBez<F, P, C>) -> Result<$name<F, P>, $crate::BezError> {
+                use $crate::BezCurve;
+                if curve.order() != $order {
+                    Err($crate::BezError::OrderMismatch($order, curve.order()))
+                } else {
+                    Ok($name::from_slice(AsRef::<[P]>::as_ref(&curve)).unwrap())
+                }
+            }
+        }
+
+        impl<F, P> ::core::convert::AsRef<[P]> for $name<F, P> 
                 where F: $crate::Float,
                       P: $crate::Point<F> {
             fn as_ref(&self) -> &[P] {
-                use std::slice;
+                use core::slice;
                 unsafe {
                     slice::from_raw_parts(self as *const $name<F, P> as *const P, $order + 1)
                 }
             }
         }
 
-        impl<F, P> ::std::convert::AsMut<[P]> for $name<F, P> 
+        impl<F, P> ::core::convert::AsMut<[P]> for $name<F, P> 
                 where F: $crate::Float,
                       P: $crate::Point<F> {
             fn as_mut(&mut self) -> &mut [P] {
-                use std::slice;
+                use core::slice;
                 unsafe {
                     slice::from_raw_parts_mut(self as *mut $name<F, P> as *mut P, $order + 1)
                 }
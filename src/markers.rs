@@ -1,8 +1,9 @@
 use num_traits::float;
 use num_traits::identities::Zero;
 use num_traits::cast::FromPrimitive;
-use std::fmt::Debug;
-use std::ops::{Add, Sub, Mul, Div};
+use core::fmt::Debug;
+use core::ops::{Add, Sub, Mul, Div};
+use core::slice;
 
 /// A helper trait to simplify float generics
 pub trait Float: float::Float + FromPrimitive + Debug {}
@@ -18,7 +19,29 @@ pub trait PVOps<F>:
 		Zero
 
 		where Self: Sized,
-			  F: Float {}
+			  F: Float {
+	/// Get the components of the point or vector as a slice. This is used by the methods that work on
+	/// each axis separately, such as `BezCurve::bounding_box`.
+	fn as_slice(&self) -> &[F];
+	/// Get the components of the point or vector as a mutable slice
+	fn as_mut_slice(&mut self) -> &mut [F];
+
+	/// Get the componentwise minimum of `self` and `other`. Like `Float::min`, a NaN component is
+	/// ignored in favor of the other one.
+	fn min(mut self, other: Self) -> Self {
+		for (c, &o) in self.as_mut_slice().iter_mut().zip(other.as_slice()) {
+			*c = c.min(o);
+		}
+		self
+	}
+	/// Get the componentwise maximum of `self` and `other`, ignoring NaN components like `min` does.
+	fn max(mut self, other: Self) -> Self {
+		for (c, &o) in self.as_mut_slice().iter_mut().zip(other.as_slice()) {
+			*c = c.max(o);
+		}
+		self
+	}
+}
 
 /// Specifies the needed traits to have a `nbez` point, as well as the vector type that this
 /// corresponds to
@@ -40,13 +63,29 @@ pub trait Vector<F: Float>:
 		Copy +
 		PVOps<F> {}
 
-impl PVOps<f32> for f32 {}
+impl PVOps<f32> for f32 {
+	fn as_slice(&self) -> &[f32] {
+		slice::from_ref(self)
+	}
+
+	fn as_mut_slice(&mut self) -> &mut [f32] {
+		slice::from_mut(self)
+	}
+}
 impl Point<f32> for f32 {
 	type Vector = f32;
 }
 impl Vector<f32> for f32 {}
 
-impl PVOps<f64> for f64 {}
+impl PVOps<f64> for f64 {
+	fn as_slice(&self) -> &[f64] {
+		slice::from_ref(self)
+	}
+
+	fn as_mut_slice(&mut self) -> &mut [f64] {
+		slice::from_mut(self)
+	}
+}
 impl Point<f64> for f64 {
 	type Vector = f64;
 }
@@ -1,96 +1,118 @@
-use std::convert::{AsRef, AsMut, From};
-use std::cell::{Cell, RefCell};
-use std::marker::PhantomData;
-use std::fmt::{Debug, Formatter};
-use std::ops::Range;
-
-
-use super::{BezCurve, Point2d, Float, Point, lerp};
-
-/// A struct that contains range information for slicing, used for slicing into the global factor
-/// vector. The reason this is used instead of stdlib's `Range` struct is that `Range` does not
-/// implement Copy, which means we have to use `RefCell`s instead of `Cell`s for interior mutability.
-#[derive(Copy, Clone)]
-struct RangeSlice {
-    start: usize,
-    end: usize
-}
-
-impl RangeSlice {
-    #[inline]
-    fn new(start: usize, end: usize) -> RangeSlice {
-        RangeSlice {
-            start: start,
-            end: end
+use core::convert::{AsRef, AsMut, From};
+use core::iter::FromIterator;
+use core::marker::PhantomData;
+use core::fmt::{Debug, Formatter};
+use alloc::vec::Vec;
+
+
+use super::{BezCurve, BezElevate, BezError, Point2d, Float, Point, lerp, dot, decasteljau_eval, decasteljau_split};
+
+/// The number of control points at which `NBez` stops being able to create curves. This is one more
+/// than the number of points in a curve of the highest order whose binomial coefficients all fit in a
+/// `u64`.
+const MAX_POINTS: usize = 69;
+
+/// The number of entries in the rows of Pascal's triangle for every order below `MAX_POINTS`.
+const TRIANGLE_LEN: usize = MAX_POINTS * (MAX_POINTS - 1) / 2;
+
+/// Build the rows of Pascal's triangle for every order below `MAX_POINTS`, one after the other. Each
+/// row is built by summing adjacent entries of the previous row, which only overflows once the
+/// coefficients themselves do.
+const fn pascal_triangle() -> [u64; TRIANGLE_LEN] {
+    let mut table = [0u64; TRIANGLE_LEN];
+    table[0] = 1;
+
+    // Row `n` has `n + 1` entries and starts at index `n(n + 1) / 2`.
+    let mut n = 1;
+    while n < MAX_POINTS - 1 {
+        let (prev, row) = ((n - 1) * n / 2, n * (n + 1) / 2);
+        table[row] = 1;
+        table[row + n] = 1;
+
+        let mut k = 1;
+        while k < n {
+            table[row + k] = match table[prev + k - 1].checked_add(table[prev + k]) {
+                Some(c) => c,
+                None => panic!("binomial coefficient overflowed u64")
+            };
+            k += 1;
         }
+        n += 1;
     }
+    table
+}
 
-    fn as_range(&self) -> Range<usize> {
-        self.start..self.end
-    }
+/// The binomial coefficients for every supported order, computed at compile time.
+static FACTORS: [u64; TRIANGLE_LEN] = pascal_triangle();
 
-    fn len(&self) -> usize {
-        self.end - self.start
-    }
+/// Get the binomial coefficients of `order`, which are the factors of a curve of that order's
+/// Bernstein polynomial.
+fn factors(order: usize) -> &'static [u64] {
+    let start = order * (order + 1) / 2;
+    &FACTORS[start..start + order + 1]
 }
 
-fn combination(n: u64, k: u64) -> u64 {
-    factorial(n) / (factorial(k) * factorial(n - k))
-}
 
-fn factorial(mut n: u64) -> u64 {
-    let mut accumulator: u64 = 1;
-    while n > 0 {
-        accumulator = accumulator.checked_mul(n).expect("Attempted to create Bézier curve with combination that overflow u64; decrease curve order");
-        n -= 1;
-    }
-    accumulator
+/// Get copies of `a` and `b` with the same order, elevating whichever curve has the lower order to the
+/// order of the other. This is needed by operations that combine the control points of two curves.
+pub fn match_orders<F, P, C1, C2>(a: &NBez<F, P, C1>, b: &NBez<F, P, C2>) -> (NBez<F, P>, NBez<F, P>)
+        where F: Float,
+              P: Point<F>,
+              C1: AsRef<[P]> + AsMut<[P]>,
+              C2: AsRef<[P]> + AsMut<[P]> {
+    let order = a.order().max(b.order());
+    (a.elevate_to(order), b.elevate_to(order))
 }
 
-/// Given the `order` and references to the `factors`, `dfactors`, and `vec` cells, update the
-/// cells to contain accurate information about the factors of the order. 
-fn update_factors(order: usize, factors: &Cell<RangeSlice>, dfactors: &Cell<RangeSlice>, vec: &RefCell<Vec<u64>>) {
-    if factors.get().len() != order + 1 {
-        let mut vec = vec.borrow_mut();
-        // Remove everything from the vector without freeing memory
-        unsafe{ vec.set_len(0) };
-
-        // The vector stores both the factors of the order and the order's derivative, and this is the
-        // length necessary to contain those factors.
-        let new_len = (order + 1) * 2 - 1;
-        if vec.capacity() < new_len {
-            let reserve_amount = new_len - vec.capacity();
-            vec.reserve(reserve_amount);
-        }
-
-        {
-            let order = order as u64;
+/// Get the curve traced by the difference between `a` and `b`, whose control points are those of `a`
+/// minus those of `b`. Interpolating the difference at `t` gives the offset from `b` to `a` at `t`, so
+/// its distance from the origin measures how far apart the curves are there. Returns `None` if the
+/// curves have different orders.
+pub fn difference<F, P, C1, C2>(a: &NBez<F, P, C1>, b: &NBez<F, P, C2>) -> Option<NBez<F, P>>
+        where F: Float,
+              P: Point<F>,
+              C1: AsRef<[P]> + AsMut<[P]>,
+              C2: AsRef<[P]> + AsMut<[P]> {
+    if a.order() != b.order() {
+        return None;
+    }
 
-            for k in 0..order + 1 {
-                vec.push(combination(order, k));
-            }
+    let (a, b): (&[P], &[P]) = (a.points.as_ref(), b.points.as_ref());
+    Some(NBez::from_container(a.iter().zip(b.iter()).map(|(pa, pb)| *pa - *pb).collect()))
+}
 
-            for k in 0..order {
-                vec.push(combination(order - 1, k));
-            }
-        }
+/// Get the binomial coefficients `order` choose `0` through `order` choose `order`, which weight the
+/// terms of the Bernstein polynomial of a curve of that order. For example, `bernstein_coefficients(4)`
+/// is `[1, 4, 6, 4, 1]`.
+///
+/// Panics if `order` is so high that the coefficients don't fit in a `u64`.
+pub fn bernstein_coefficients(order: usize) -> Vec<u64> {
+    assert!(order < MAX_POINTS - 1, "Bernstein coefficients of order {} overflow u64", order);
+    factors(order).to_vec()
+}
 
-        factors.set(RangeSlice::new(0, order + 1));
-        dfactors.set(RangeSlice::new(order + 1, vec.len()));
+/// Get the coefficients that weight the terms of the derivative of a curve of `order`, which is a
+/// Bernstein polynomial one order lower. These are the binomial coefficients of `order - 1`, each
+/// multiplied by `order`. A zero-order curve has no derivative terms, so it gets no coefficients.
+///
+/// Panics if `order` is so high that the coefficients don't fit in a `u64`.
+pub fn bernstein_derivative_coefficients(order: usize) -> Vec<u64> {
+    if order == 0 {
+        return Vec::new();
     }
+    bernstein_coefficients(order - 1).iter()
+        .map(|c| c.checked_mul(order as u64).expect("Bernstein derivative coefficient overflowed u64"))
+        .collect()
 }
 
 
-/// An n-order bezier curve. The `from_slice`, `split`, and `split_unbounded` functions currently do not work.
+/// An n-order bezier curve. The `from_slice` function currently does not work.
 #[derive(Clone)]
 pub struct NBez<F, P = Point2d<F>, C = Vec<P>> 
         where F: Float,
               P: Point<F>,
               C: AsRef<[P]> + AsMut<[P]> {
     points: C,
-    factor_vec: RefCell<Vec<u64>>,
-    factors: Cell<RangeSlice>,
-    dfactors: Cell<RangeSlice>,
     phantom: PhantomData<(F, P)>
 }
 
@@ -103,21 +125,41 @@ impl<F, P, C> From<C> for NBez<F, P, C>
     }
 }
 
+impl<F, P> NBez<F, P, Vec<P>>
+        where F: Float,
+              P: Point<F> {
+    /// Collect the points from `iter` into a curve, failing if there are too many points for a
+    /// curve to be created from them. Collecting the curve with `collect` panics instead.
+    pub fn try_from_iter<I: IntoIterator<Item = P>>(iter: I) -> Result<NBez<F, P, Vec<P>>, BezError> {
+        let points: Vec<P> = iter.into_iter().collect();
+        if points.len() >= MAX_POINTS {
+            Err(BezError::OrderTooHigh(points.len() - 1))
+        } else {
+            Ok(NBez::from_container(points))
+        }
+    }
+}
+
+impl<F, P> FromIterator<P> for NBez<F, P, Vec<P>>
+        where F: Float,
+              P: Point<F> {
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> NBez<F, P, Vec<P>> {
+        NBez::from_container(iter.into_iter().collect())
+    }
+}
+
 impl<F, P, C> NBez<F, P, C>
         where F: Float,
               P: Point<F>,
               C: AsRef<[P]> + AsMut<[P]> {
     #[inline]
     pub fn from_container(points: C) -> NBez<F, P, C> {
-        if points.as_ref().len() >= 22 {
-            panic!("Cannot create Bézier polynomials with an order >= 21")
+        if points.as_ref().len() >= MAX_POINTS {
+            panic!("Cannot create Bézier polynomials with an order >= {}", MAX_POINTS - 1)
         }
 
         NBez {
             points: points,
-            factor_vec: RefCell::new(Vec::new()),
-            factors: Cell::new(RangeSlice::new(0, 0)),
-            dfactors: Cell::new(RangeSlice::new(0, 0)),
             phantom: PhantomData
         }
     }
@@ -126,6 +168,157 @@ impl<F, P, C> NBez<F, P, C>
     pub fn unwrap(self) -> C {
         self.points
     }
+
+    /// Perform interpolation on the curve with no range bounds, using repeated linear interpolation
+    /// (the de Casteljau algorithm) instead of the Bernstein polynomial used by `interp_unbounded`.
+    ///
+    /// This is slower than `interp_unbounded`, but it never multiplies by binomial coefficients, so it
+    /// stays numerically stable for high-order curves. Prefer this method when the order is large (more
+    /// than about 10) or precision matters more than speed.
+    pub fn interp_decasteljau(&self, t: F) -> P {
+        decasteljau_eval(self.points.as_ref(), t)
+    }
+
+    /// Interpolate the curve at every parameter in `ts` with no range bounds, writing each point to
+    /// the same index in `out`. This gives the same points as calling `interp_unbounded` for each
+    /// parameter, but only looks up and converts the binomial coefficients once.
+    ///
+    /// Panics if `out` and `ts` have different lengths.
+    pub fn interp_batch(&self, ts: &[F], out: &mut [P]) {
+        assert_eq!(ts.len(), out.len(), "interp_batch output buffer must be the same length as the parameters");
+
+        let points = self.points.as_ref();
+        let weights: Vec<F> = factors(self.order()).iter().map(|f| F::from_u64(*f).unwrap()).collect();
+        let order = weights.len() - 1;
+
+        for (&t, out) in ts.iter().zip(out.iter_mut()) {
+            let t1 = F::from_f32(1.0).unwrap() - t;
+            let mut acc = P::zero();
+
+            for (factor, point) in points.iter().enumerate() {
+                acc = acc + *point *
+                            t.powi(factor as i32) *
+                            t1.powi((order - factor) as i32) *
+                            weights[factor];
+            }
+            *out = acc;
+        }
+    }
+
+    /// Get the hodograph of the curve, which is the curve traced by its derivative. Interpolating the
+    /// hodograph at `t` gives the same point as `slope_unbounded` gives vector. The hodograph of a
+    /// zero-order curve is a single point at the origin.
+    pub fn hodograph(&self) -> NBez<F, P, Vec<P>> {
+        let points = self.points.as_ref();
+        if points.len() < 2 {
+            return NBez::from_container(vec![P::zero()]);
+        }
+
+        let order_f = F::from_usize(self.order()).unwrap();
+        NBez::from_container(points.windows(2).map(|w| (w[1] - w[0]) * order_f).collect())
+    }
+
+    /// Elevate the curve until its order is `target_order`, getting a curve that gives the same results
+    /// upon interpolation. If the curve's order is already at least `target_order`, the returned curve
+    /// has the same points as this one.
+    pub fn elevate_to(&self, target_order: usize) -> NBez<F, P, Vec<P>> {
+        let mut curve = NBez::from_container(self.points.as_ref().to_vec());
+        while curve.order() < target_order {
+            curve = curve.elevate();
+        }
+        curve
+    }
+
+    /// Blend between this curve and `other` by linearly interpolating their control points, with an `s`
+    /// of `0.0` giving this curve and an `s` of `1.0` giving `other`. If the curves have different
+    /// orders, the lower-order curve gets elevated to the order of the higher-order one first.
+    pub fn blend<D>(&self, other: &NBez<F, P, D>, s: F) -> NBez<F, P, Vec<P>>
+            where D: AsRef<[P]> + AsMut<[P]> {
+        let (a, b) = match_orders(self, other);
+
+        let a_points: &[P] = a.as_ref();
+        let b_points: &[P] = b.as_ref();
+        NBez::from_container(a_points.iter().zip(b_points.iter()).map(|(a, b)| lerp(*a, *b, s)).collect())
+    }
+
+    /// Reduce the curve's order by one, returning the lower-order curve that best approximates this one
+    /// along with the approximation error. This is the inverse of `elevate`.
+    ///
+    /// The reduced control points are the least-squares solution to elevating them back into this
+    /// curve's control points. The returned error is the largest distance between this curve's control
+    /// points and those of the elevated reduction, which bounds the distance between the curves. If this
+    /// curve was created by elevating a lower-order curve, the reduction is exact.
+    pub fn reduce(&self) -> (NBez<F, P, Vec<P>>, F) {
+        let points = self.points.as_ref();
+        let order = self.order();
+        if order == 0 {
+            return (NBez::from_container(points.to_vec()), F::zero());
+        }
+
+        // Elevating the `order` points of the reduced curve produces `points.len()` points, with point
+        // `i` being `elevation(i, i - 1) * reduced[i - 1] + elevation(i, i) * reduced[i]`.
+        let order_f = F::from_usize(order).unwrap();
+        let elevation = |row: usize, col: usize| {
+            if col + 1 == row {
+                F::from_usize(row).unwrap() / order_f
+            } else if col == row {
+                F::one() - F::from_usize(row).unwrap() / order_f
+            } else {
+                F::zero()
+            }
+        };
+
+        // Build and solve the normal equations, `(EᵀE) reduced = Eᵀ points`.
+        let mut matrix = vec![vec![F::zero(); order]; order];
+        let mut rhs = vec![P::zero(); order];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                *entry = (0..order + 1).fold(F::zero(), |acc, k| acc + elevation(k, i) * elevation(k, j));
+            }
+            rhs[i] = (0..order + 1).fold(P::zero(), |acc, k| acc + points[k] * elevation(k, i));
+        }
+        let reduced = solve_linear(matrix, rhs);
+
+        let mut error = F::zero();
+        for (k, point) in points.iter().enumerate() {
+            let elevated = (0..order).fold(P::zero(), |acc, i| acc + reduced[i] * elevation(k, i));
+            let diff = *point - elevated;
+            error = error.max(dot(diff.as_slice(), diff.as_slice()).sqrt());
+        }
+
+        (NBez::from_container(reduced), error)
+    }
+}
+
+/// Solve the linear system `matrix * x = rhs` with Gaussian elimination, where `matrix` is symmetric
+/// positive-definite.
+fn solve_linear<F: Float, P: Point<F>>(mut matrix: Vec<Vec<F>>, mut rhs: Vec<P>) -> Vec<P> {
+    let n = rhs.len();
+
+    for col in 0..n {
+        let (upper, lower) = matrix.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+
+        for (row, lower_row) in lower.iter_mut().enumerate().map(|(i, r)| (i + col + 1, r)) {
+            let factor = lower_row[col] / pivot_row[col];
+            if factor != F::zero() {
+                for (entry, pivot) in lower_row[col..].iter_mut().zip(&pivot_row[col..]) {
+                    *entry = *entry - *pivot * factor;
+                }
+                rhs[row] = rhs[row] - rhs[col] * factor;
+            }
+        }
+    }
+
+    let mut x = vec![P::zero(); n];
+    for row in (0..n).rev() {
+        let mut acc = rhs[row];
+        for k in row + 1..n {
+            acc = acc - x[k] * matrix[row][k];
+        }
+        x[row] = acc / matrix[row][row];
+    }
+    x
 }
 
 impl<F, P, C> BezCurve<F> for NBez<F, P, C> 
@@ -133,7 +326,7 @@ impl<F, P, C> BezCurve<F> for NBez<F, P, C>
               P: Point<F>,
               C: AsRef<[P]> + AsMut<[P]> {
     type Point = P;
-    type Elevated = NBez<F, P, Vec<P>>;
+    type Split = NBez<F, P, Vec<P>>;
 
     /// Currently non-functional; returns `None`
     fn from_slice(_: &[P]) -> Option<NBez<F, P, C>> {
@@ -142,9 +335,7 @@ impl<F, P, C> BezCurve<F> for NBez<F, P, C>
 
     fn interp_unbounded(&self, t: F) -> P {
         let points = self.points.as_ref();
-        update_factors(self.order(), &self.factors, &self.dfactors, &self.factor_vec);
-        let factors = &self.factor_vec.borrow()[self.factors.get().as_range()];
-
+        let factors = factors(self.order());
 
         let t1 = F::from_f32(1.0).unwrap() - t;
         let order = factors.len() - 1;
@@ -163,8 +354,11 @@ impl<F, P, C> BezCurve<F> for NBez<F, P, C>
 
     fn slope_unbounded(&self, t: F) -> P::Vector {
         let points = self.points.as_ref();
-        update_factors(self.order(), &self.factors, &self.dfactors, &self.factor_vec);
-        let dfactors = &self.factor_vec.borrow()[self.dfactors.get().as_range()];
+        // A zero-order curve is a single point, so it doesn't have any slope.
+        if self.order() == 0 {
+            return P::zero().into();
+        }
+        let dfactors = factors(self.order() - 1);
 
         let t1 = F::from_f32(1.0).unwrap() - t;
         let order = dfactors.len() - 1;
@@ -176,13 +370,29 @@ impl<F, P, C> BezCurve<F> for NBez<F, P, C>
             acc = acc + (point - point_last) *
                         t.powi(factor as i32) *
                         t1.powi((order-factor) as i32) *
-                        F::from_u64(dfactors[factor] * (order + 1) as u64).unwrap();
+                        F::from_u64(dfactors[factor]).unwrap() * F::from_usize(order + 1).unwrap();
             point_last = point;
             factor += 1;
         }            
         acc.into()
     }
 
+    fn split_unbounded(&self, t: F) -> (NBez<F, P, Vec<P>>, NBez<F, P, Vec<P>>) {
+        let (left, right) = decasteljau_split(self.points.as_ref(), t);
+        (NBez::from_container(left), NBez::from_container(right))
+    }
+
+    fn order(&self) -> usize {
+        self.points.as_ref().len()-1
+    }
+}
+
+impl<F, P, C> BezElevate<F> for NBez<F, P, C>
+        where F: Float,
+              P: Point<F>,
+              C: AsRef<[P]> + AsMut<[P]> {
+    type Elevated = NBez<F, P, Vec<P>>;
+
     fn elevate(&self) -> NBez<F, P, Vec<P>> {        
         let points = self.points.as_ref();
         let order = self.order() + 1;
@@ -202,20 +412,6 @@ impl<F, P, C> BezCurve<F> for NBez<F, P, C>
         el_points.push(points[self.order()]);
         NBez::from_container(el_points)
     }
-
-    /// Currently non-functional; returns `None`
-    fn split(&self, _: F) -> Option<(NBez<F, P, C>, NBez<F, P, C>)> {
-        None
-    }
-
-    /// Currently non-functional; panics with unimplemented
-    fn split_unbounded(&self, _: F) -> (NBez<F, P, C>, NBez<F, P, C>) {
-        unimplemented!()
-    }
-
-    fn order(&self) -> usize {
-        self.points.as_ref().len()-1
-    }
 }
 
 impl<F, P, C> AsRef<C> for NBez<F, P, C>
@@ -254,11 +450,39 @@ impl<F, P, C> AsMut<[P]> for NBez<F, P, C>
     }
 }
 
+/// Only the control points are serialized.
+#[cfg(feature = "serde")]
+impl<F, P, C> ::serde::Serialize for NBez<F, P, C>
+        where F: Float,
+              P: Point<F> + ::serde::Serialize,
+              C: AsRef<[P]> + AsMut<[P]> {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.points.as_ref().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, F, P, C> ::serde::Deserialize<'de> for NBez<F, P, C>
+        where F: Float,
+              P: Point<F>,
+              C: AsRef<[P]> + AsMut<[P]> + ::serde::Deserialize<'de> {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<NBez<F, P, C>, D::Error> {
+        use serde::de::Error;
+
+        let points = C::deserialize(deserializer)?;
+        if points.as_ref().len() >= MAX_POINTS {
+            Err(D::Error::custom(format!("Cannot create Bézier polynomials with an order >= {}", MAX_POINTS - 1)))
+        } else {
+            Ok(NBez::from_container(points))
+        }
+    }
+}
+
 impl<F, P, C> Debug for NBez<F, P, C>
         where F: Float,
               P: Point<F>,
               C: AsRef<[P]> + AsMut<[P]> + Debug {
-    fn fmt(&self, f: &mut Formatter) -> Result<(), ::std::fmt::Error> {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), ::core::fmt::Error> {
         f.debug_tuple("NBez")
             .field(&self.points)
             .finish()
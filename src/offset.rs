@@ -0,0 +1,82 @@
+use alloc::vec::Vec;
+
+use super::{BezCurve, BezCurve2d, NBez, Point2d, Vector2d, Float};
+
+/// A cubic piece of an offset curve.
+type Cubic<F> = NBez<F, Point2d<F>, Vec<Point2d<F>>>;
+
+/// The maximum number of times a piece of the offset gets subdivided while refining it.
+const MAX_DEPTH: u32 = 16;
+
+/// Get the unit normal of `curve` at `t`, pointing to the left of the direction of travel. Where the
+/// curve's speed is zero, the direction of its second derivative is used as the direction of travel
+/// instead.
+fn unit_normal<F, B>(curve: &B, t: F) -> Vector2d<F>
+        where F: Float,
+              B: BezCurve<F, Point = Point2d<F>> {
+    let mut tangent = curve.slope_unbounded(t);
+    if tangent.len() == F::zero() {
+        tangent = curve.slope2_unbounded(t);
+    }
+
+    let len = tangent.len();
+    if len == F::zero() {
+        tangent
+    } else {
+        tangent.perp() / len
+    }
+}
+
+/// Get the point that's `distance` away from `curve` at `t`, along with the derivative of the offset
+/// curve at that point.
+fn offset_point<F, B>(curve: &B, t: F, distance: F) -> (Point2d<F>, Vector2d<F>)
+        where F: Float,
+              B: BezCurve<F, Point = Point2d<F>> {
+    let point = curve.interp_unbounded(t) + Point2d::from(unit_normal(curve, t) * distance);
+    // Moving along the normal scales the speed of the curve by `1 - distance * curvature`.
+    let slope = curve.slope_unbounded(t) * (F::one() - distance * curve.curvature_unbounded(t));
+    (point, slope)
+}
+
+/// Approximate the offset of `curve` between `t0` and `t1` with a cubic that matches the offset's
+/// position and derivative at both ends, subdividing until it's within `tolerance`.
+fn offset_piece<F, B>(curve: &B, t0: F, t1: F, distance: F, tolerance: F, depth: u32, out: &mut Vec<Cubic<F>>)
+        where F: Float,
+              B: BezCurve<F, Point = Point2d<F>> {
+    let third = (t1 - t0) / F::from_u32(3).unwrap();
+    let (start, start_slope) = offset_point(curve, t0, distance);
+    let (end, end_slope) = offset_point(curve, t1, distance);
+    let piece = NBez::from_container(vec![
+        start,
+        start + Point2d::from(start_slope * third),
+        end - Point2d::from(end_slope * third),
+        end
+    ]);
+
+    let within_tolerance = (1..4).all(|i| {
+        let s = F::from_u32(i).unwrap() / F::from_u32(4).unwrap();
+        let (expected, _) = offset_point(curve, t0 + (t1 - t0) * s, distance);
+        expected.distance(piece.interp_unbounded(s)) <= tolerance
+    });
+
+    if within_tolerance || depth >= MAX_DEPTH {
+        out.push(piece);
+    } else {
+        let mid = (t0 + t1) / F::from_u32(2).unwrap();
+        offset_piece(curve, t0, mid, distance, tolerance, depth + 1, out);
+        offset_piece(curve, mid, t1, distance, tolerance, depth + 1, out);
+    }
+}
+
+/// Approximate the curve that's `distance` away from `curve` with a sequence of cubics. The curve is
+/// first split into monotonic pieces at its extrema, and each piece is subdivided further wherever its
+/// cubic approximation is more than `tolerance` away from the true offset.
+pub fn offset<F, B>(curve: &B, distance: F, tolerance: F) -> Vec<Cubic<F>>
+        where F: Float,
+              B: BezCurve<F, Point = Point2d<F>> {
+    let mut pieces = Vec::new();
+    for span in curve.extrema().windows(2) {
+        offset_piece(curve, span[0], span[1], distance, tolerance, 0, &mut pieces);
+    }
+    pieces
+}
@@ -0,0 +1,276 @@
+use core::fmt::{self, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Sub, Mul, Div, Neg, Index, IndexMut};
+
+use num_traits::identities::Zero;
+
+use super::{Point, Vector, PVOps, Float, HashablePoint, float_key};
+
+macro_rules! n_dim_pointvector {
+    (ops $lhs:ident; $rhs:ident) => {
+        impl<F: Float, const D: usize> Add<$rhs<F, D>> for $lhs<F, D> {
+            type Output = $lhs<F, D>;
+
+            fn add(mut self, rhs: $rhs<F, D>) -> $lhs<F, D> {
+                for (c, r) in self.0.iter_mut().zip(rhs.0.iter()) {
+                    *c = *c + *r;
+                }
+                self
+            }
+        }
+
+        impl<F: Float, const D: usize> Sub<$rhs<F, D>> for $lhs<F, D> {
+            type Output = $lhs<F, D>;
+
+            fn sub(mut self, rhs: $rhs<F, D>) -> $lhs<F, D> {
+                for (c, r) in self.0.iter_mut().zip(rhs.0.iter()) {
+                    *c = *c - *r;
+                }
+                self
+            }
+        }
+
+        impl<F: Float, const D: usize> Mul<$rhs<F, D>> for $lhs<F, D> {
+            type Output = $lhs<F, D>;
+
+            fn mul(mut self, rhs: $rhs<F, D>) -> $lhs<F, D> {
+                for (c, r) in self.0.iter_mut().zip(rhs.0.iter()) {
+                    *c = *c * *r;
+                }
+                self
+            }
+        }
+
+        impl<F: Float, const D: usize> Div<$rhs<F, D>> for $lhs<F, D> {
+            type Output = $lhs<F, D>;
+
+            fn div(mut self, rhs: $rhs<F, D>) -> $lhs<F, D> {
+                for (c, r) in self.0.iter_mut().zip(rhs.0.iter()) {
+                    *c = *c / *r;
+                }
+                self
+            }
+        }
+    };
+
+    (struct $doc:expr; $name:ident $sibling:ident) => {
+        #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+        #[doc=$doc]
+        pub struct $name<F: Float, const D: usize>(pub [F; D]);
+
+        impl<F: Float, const D: usize> $name<F, D> {
+            /// Create a new instance of this struct with the given components.
+            pub fn new(components: [F; D]) -> $name<F, D> {
+                $name(components)
+            }
+
+            /// Returns true if every component of `self` is within `epsilon` of the corresponding
+            /// component of `other`.
+            pub fn approx_eq(self, other: $name<F, D>, epsilon: F) -> bool {
+                self.0.iter().zip(other.0.iter()).all(|(a, b)| (*a - *b).abs() <= epsilon)
+            }
+
+            /// Get an iterator over the components of this struct.
+            pub fn components(&self) -> ::core::iter::Cloned<::core::slice::Iter<'_, F>> {
+                self.0.iter().cloned()
+            }
+        }
+
+        impl<F: Float, const D: usize> Default for $name<F, D> {
+            fn default() -> $name<F, D> {
+                $name::zero()
+            }
+        }
+
+        impl<F: Float, const D: usize> From<[F; D]> for $name<F, D> {
+            fn from(array: [F; D]) -> $name<F, D> {
+                $name(array)
+            }
+        }
+
+        impl<F: Float, const D: usize> From<$name<F, D>> for [F; D] {
+            fn from(pv: $name<F, D>) -> [F; D] {
+                pv.0
+            }
+        }
+
+        impl<F: Float, const D: usize> From<$sibling<F, D>> for $name<F, D> {
+            fn from(sib: $sibling<F, D>) -> $name<F, D> {
+                $name(sib.0)
+            }
+        }
+
+        impl<F: Float, const D: usize> AsRef<[F]> for $name<F, D> {
+            fn as_ref(&self) -> &[F] {
+                &self.0
+            }
+        }
+
+        impl<F: Float, const D: usize> AsMut<[F]> for $name<F, D> {
+            fn as_mut(&mut self) -> &mut [F] {
+                &mut self.0
+            }
+        }
+
+        /// Formats the components in parentheses, like `(1, 2)`. Formatting flags such as precision get
+        /// applied to each component.
+        impl<F: Float + Display, const D: usize> Display for $name<F, D> {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                f.write_str("(")?;
+                for (i, c) in self.components().enumerate() {
+                    if i != 0 {
+                        f.write_str(", ")?;
+                    }
+                    Display::fmt(&c, f)?;
+                }
+                f.write_str(")")
+            }
+        }
+
+        impl<F: Float, const D: usize> PartialEq for HashablePoint<$name<F, D>> {
+            fn eq(&self, other: &HashablePoint<$name<F, D>>) -> bool {
+                self.0.components().zip(other.0.components()).all(|(a, b)| float_key(a) == float_key(b))
+            }
+        }
+
+        impl<F: Float, const D: usize> Eq for HashablePoint<$name<F, D>> {}
+
+        impl<F: Float, const D: usize> Hash for HashablePoint<$name<F, D>> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                for c in self.0.components() {
+                    float_key(c).hash(state);
+                }
+            }
+        }
+
+        impl<F: Float, const D: usize> Index<usize> for $name<F, D> {
+            type Output = F;
+
+            fn index(&self, index: usize) -> &F {
+                if index >= D {
+                    panic!("Index {} is out of range for {} with {} dimensions", index, stringify!($name), D);
+                }
+                &self.0[index]
+            }
+        }
+
+        impl<F: Float, const D: usize> IndexMut<usize> for $name<F, D> {
+            fn index_mut(&mut self, index: usize) -> &mut F {
+                if index >= D {
+                    panic!("Index {} is out of range for {} with {} dimensions", index, stringify!($name), D);
+                }
+                &mut self.0[index]
+            }
+        }
+
+        impl<F: Float, const D: usize> Zero for $name<F, D> {
+            fn zero() -> $name<F, D> {
+                $name([F::zero(); D])
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0.iter().all(|c| c.is_zero())
+            }
+        }
+
+        impl<F: Float, const D: usize> Mul<F> for $name<F, D> {
+            type Output = $name<F, D>;
+
+            fn mul(mut self, rhs: F) -> $name<F, D> {
+                for c in self.0.iter_mut() {
+                    *c = *c * rhs;
+                }
+                self
+            }
+        }
+
+        impl<F: Float, const D: usize> Div<F> for $name<F, D> {
+            type Output = $name<F, D>;
+
+            fn div(mut self, rhs: F) -> $name<F, D> {
+                for c in self.0.iter_mut() {
+                    *c = *c / rhs;
+                }
+                self
+            }
+        }
+
+        impl<F: Float, const D: usize> Neg for $name<F, D> {
+            type Output = $name<F, D>;
+
+            fn neg(mut self) -> $name<F, D> {
+                for c in self.0.iter_mut() {
+                    *c = -*c;
+                }
+                self
+            }
+        }
+
+        impl<F: Float, const D: usize> PVOps<F> for $name<F, D> {
+            fn as_slice(&self) -> &[F] {
+                &self.0
+            }
+
+            fn as_mut_slice(&mut self) -> &mut [F] {
+                &mut self.0
+            }
+        }
+
+        n_dim_pointvector!(ops $name; $sibling);
+        n_dim_pointvector!(ops $name; $name);
+    }
+}
+
+n_dim_pointvector!(struct "A point with any number of dimensions, given by `D`. Unlike the named point \
+                           types, the components are stored in an array rather than in named fields.";
+                   PointN VectorN);
+n_dim_pointvector!(struct "A vector with any number of dimensions, given by `D`. Unlike the named vector \
+                           types, the components are stored in an array rather than in named fields.";
+                   VectorN PointN);
+
+impl<F: Float, const D: usize> VectorN<F, D> {
+    /// Get the length of this vector, with the Pythagorean theorem
+    pub fn len(self) -> F {
+        self.dot(self).sqrt()
+    }
+
+    /// Return a vector that points in the same direction as `self`, but has a length of one.
+    pub fn normalize(self) -> VectorN<F, D> {
+        self / self.len()
+    }
+
+    /// Get the dot product of `self` and `rhs`.
+    pub fn dot(self, rhs: VectorN<F, D>) -> F {
+        self.0.iter().zip(rhs.0.iter()).fold(F::zero(), |sum, (a, b)| sum + *a * *b)
+    }
+
+    /// Get the projection of `self` onto `other`, which is the part of `self` that points in the same
+    /// direction as `other`. If `other` has a length of zero, returns a zero vector.
+    pub fn project_onto(self, other: VectorN<F, D>) -> VectorN<F, D> {
+        let other_len_sq = other.dot(other);
+        if other_len_sq == F::zero() {
+            other
+        } else {
+            other * (self.dot(other) / other_len_sq)
+        }
+    }
+}
+
+impl<F: Float, const D: usize> PointN<F, D> {
+    /// Get the euclidean distance between `self` and `other`.
+    pub fn distance(self, other: PointN<F, D>) -> F {
+        VectorN::from(self - other).len()
+    }
+
+    /// Get the square of the euclidean distance between `self` and `other`. This avoids taking a
+    /// square root, so it's cheaper than `distance` when only comparing distances.
+    pub fn distance_squared(self, other: PointN<F, D>) -> F {
+        let diff = VectorN::from(self - other);
+        diff.dot(diff)
+    }
+}
+
+impl<F: Float, const D: usize> Point<F> for PointN<F, D> {
+    type Vector = VectorN<F, D>;
+}
+impl<F: Float, const D: usize> Vector<F> for VectorN<F, D> {}
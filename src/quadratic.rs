@@ -0,0 +1,50 @@
+use alloc::vec::Vec;
+
+use super::{BezCurve, Bez2o, Bez3o, Point2d, Vector2d, Float};
+
+/// The maximum number of times a piece of the cubic gets subdivided while approximating it.
+const MAX_DEPTH: u32 = 16;
+
+/// Approximate `curve` with quadratics, subdividing it until each quadratic is within `tolerance`.
+fn approximate<F: Float>(curve: &Bez3o<F, Point2d<F>>, tolerance: F, depth: u32, out: &mut Vec<Bez2o<F, Point2d<F>>>) {
+    let three = F::from_u32(3).unwrap();
+
+    // The quadratic with its control point at the average of the ones where the tangent lines at the
+    // ends would meet is at most `√3/36 |end - 3 ctrl1 + 3 ctrl0 - start|` away from the cubic.
+    let third_difference = Vector2d::from(curve.end - curve.start) + Vector2d::from(curve.ctrl0 - curve.ctrl1) * three;
+    let error = three.sqrt() / F::from_u32(36).unwrap() * third_difference.len();
+
+    if error <= tolerance || depth >= MAX_DEPTH {
+        let ctrl = (curve.ctrl0 * three - curve.start + curve.ctrl1 * three - curve.end) / F::from_u32(4).unwrap();
+        out.push(Bez2o::new(curve.start, ctrl, curve.end));
+    } else {
+        let (left, right) = curve.split_unbounded(F::from_f32(0.5).unwrap());
+        approximate(&left, tolerance, depth + 1, out);
+        approximate(&right, tolerance, depth + 1, out);
+    }
+}
+
+impl<F: Float> Bez3o<F, Point2d<F>> {
+    /// Approximate this curve with a sequence of quadratic curves, each of which is within `tolerance`
+    /// of the part of the curve it replaces. The curve is first split at its inflections, as a
+    /// quadratic can't change the direction it bends in, and each piece is then subdivided until its
+    /// approximation is accurate enough.
+    pub fn to_quadratics(&self, tolerance: F) -> Vec<Bez2o<F, Point2d<F>>> {
+        let mut quadratics = Vec::new();
+        let mut rest = *self;
+        let mut start = F::zero();
+
+        for t in self.inflections() {
+            if t <= start || t >= F::one() {
+                continue;
+            }
+            let (piece, remainder) = rest.split_unbounded((t - start) / (F::one() - start));
+            approximate(&piece, tolerance, 0, &mut quadratics);
+            rest = remainder;
+            start = t;
+        }
+        approximate(&rest, tolerance, 0, &mut quadratics);
+
+        quadratics
+    }
+}
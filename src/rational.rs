@@ -0,0 +1,124 @@
+use core::convert::{AsRef, AsMut};
+use alloc::vec::Vec;
+
+use super::{Point2d, Float, Point, decasteljau_eval};
+use super::roots::eval;
+
+/// A rational bezier curve, where each control point has a weight that controls how strongly it pulls
+/// the curve towards itself. Unlike polynomial curves, rational curves can exactly represent conic
+/// sections such as circular arcs. A curve whose weights are all equal is identical to the polynomial
+/// curve with the same control points.
+#[derive(Debug, Clone)]
+pub struct RatBez<F, P = Point2d<F>>
+        where F: Float,
+              P: Point<F> {
+    points: Vec<P>,
+    weights: Vec<F>
+}
+
+impl<F, P> RatBez<F, P>
+        where F: Float,
+              P: Point<F> {
+    /// Create a curve from its control points and their weights. Panics if there are no points, or if
+    /// the number of weights doesn't match the number of points.
+    pub fn new(points: Vec<P>, weights: Vec<F>) -> RatBez<F, P> {
+        if points.is_empty() {
+            panic!("Cannot create a rational Bézier curve with no points")
+        }
+        if points.len() != weights.len() {
+            panic!("Rational Bézier curve has {} points but {} weights", points.len(), weights.len())
+        }
+
+        RatBez {
+            points,
+            weights
+        }
+    }
+
+    /// Get the weights of the curve's control points.
+    #[inline]
+    pub fn weights(&self) -> &[F] {
+        &self.weights
+    }
+
+    /// Get mutable access to the weights of the curve's control points.
+    #[inline]
+    pub fn weights_mut(&mut self) -> &mut [F] {
+        &mut self.weights
+    }
+
+    /// Unwrap the curve, returning its control points and weights.
+    #[inline]
+    pub fn unwrap(self) -> (Vec<P>, Vec<F>) {
+        (self.points, self.weights)
+    }
+
+    /// Get the order of the curve.
+    #[inline]
+    pub fn order(&self) -> usize {
+        self.points.len() - 1
+    }
+
+    /// Perform interpolation on the curve for the given `t`, bounded on `0.0` to `1.0` inclusive.
+    /// Returns `None` if `t` is not within bounds.
+    pub fn interp(&self, t: F) -> Option<P> {
+        check_t_bounds!(t);
+        Some(self.interp_unbounded(t))
+    }
+
+    /// Perform interpolation on the curve with no range bounds. This is the weighted sum of the control
+    /// points divided by the sum of the weights, both taken with the curve's Bernstein polynomial.
+    pub fn interp_unbounded(&self, t: F) -> P {
+        decasteljau_eval(&self.weighted_points(), t) / eval(&self.weights, t)
+    }
+
+    /// Get the slope for the given `t`, bounded on `0.0` to `1.0` inclusive. Returns `None` if `t` is
+    /// not within bounds.
+    pub fn slope(&self, t: F) -> Option<P::Vector> {
+        check_t_bounds!(t);
+        Some(self.slope_unbounded(t))
+    }
+
+    /// Get the slope for the given `t` with no range bounds, using the quotient rule on the weighted
+    /// point sum and the weight sum.
+    pub fn slope_unbounded(&self, t: F) -> P::Vector {
+        let order = self.order();
+        if order == 0 {
+            return P::zero().into();
+        }
+        let order_f = F::from_usize(order).unwrap();
+
+        let weighted = self.weighted_points();
+        let numer = decasteljau_eval(&weighted, t);
+        let denom = eval(&self.weights, t);
+
+        let weighted_diffs: Vec<P> = weighted.windows(2).map(|w| w[1] - w[0]).collect();
+        let weight_diffs: Vec<F> = self.weights.windows(2).map(|w| w[1] - w[0]).collect();
+        let dnumer = decasteljau_eval(&weighted_diffs, t) * order_f;
+        let ddenom = eval(&weight_diffs, t) * order_f;
+
+        ((dnumer - numer / denom * ddenom) / denom).into()
+    }
+
+    /// Get the control points multiplied by their weights, which are the control points of the curve's
+    /// numerator polynomial.
+    fn weighted_points(&self) -> Vec<P> {
+        self.points.iter().zip(self.weights.iter()).map(|(p, w)| *p * *w).collect()
+    }
+}
+
+impl<F, P> AsRef<[P]> for RatBez<F, P>
+        where F: Float,
+              P: Point<F> {
+    fn as_ref(&self) -> &[P] {
+        &self.points
+    }
+}
+
+impl<F, P> AsMut<[P]> for RatBez<F, P>
+        where F: Float,
+              P: Point<F> {
+    fn as_mut(&mut self) -> &mut [P] {
+        &mut self.points
+    }
+}
@@ -0,0 +1,131 @@
+use alloc::vec::Vec;
+
+use super::Float;
+
+/// The maximum number of times an interval gets subdivided while isolating roots. Intervals that
+/// still contain more than one potential root after this many subdivisions are assumed to contain
+/// a repeated root, which gets placed at the interval's midpoint.
+const MAX_DEPTH: u32 = 40;
+
+/// Evaluate the one-dimensional bezier polynomial with the coefficients `coeffs` at `t`, using the
+/// de Casteljau algorithm.
+pub fn eval<F: Float>(coeffs: &[F], t: F) -> F {
+    let t1 = F::one() - t;
+    let mut pass = coeffs.to_vec();
+
+    for len in (1..pass.len()).rev() {
+        for i in 0..len {
+            pass[i] = pass[i] * t1 + pass[i + 1] * t;
+        }
+    }
+    pass[0]
+}
+
+/// Split the one-dimensional bezier polynomial with the coefficients `coeffs` in half.
+fn bisect<F: Float>(coeffs: &[F]) -> (Vec<F>, Vec<F>) {
+    let half = F::from_f32(0.5).unwrap();
+    let mut left = Vec::with_capacity(coeffs.len());
+    let mut right = Vec::with_capacity(coeffs.len());
+    let mut pass = coeffs.to_vec();
+
+    for len in (0..pass.len()).rev() {
+        left.push(pass[0]);
+        right.push(pass[len]);
+
+        for i in 0..len {
+            pass[i] = (pass[i] + pass[i + 1]) * half;
+        }
+    }
+
+    right.reverse();
+    (left, right)
+}
+
+/// Count the number of sign changes in the coefficients, ignoring any zeros. Because of the variation
+/// diminishing property of bezier curves, this is an upper bound on the number of roots of the
+/// polynomial.
+fn sign_changes<F: Float>(coeffs: &[F]) -> usize {
+    let zero = F::zero();
+    let mut changes = 0;
+    let mut last_positive = None;
+
+    for &c in coeffs.iter().filter(|c| **c != zero) {
+        let positive = c > zero;
+        if last_positive.is_some_and(|l| l != positive) {
+            changes += 1;
+        }
+        last_positive = Some(positive);
+    }
+    changes
+}
+
+/// Find the root of a polynomial which is known to have exactly one root in `0.0` to `1.0` by
+/// bisecting the parameter range.
+fn bisect_root<F: Float>(coeffs: &[F]) -> F {
+    let zero = F::zero();
+    let half = F::from_f32(0.5).unwrap();
+    let start_positive = coeffs[0] > zero;
+
+    let (mut lo, mut hi) = (zero, F::one());
+    for _ in 0..64 {
+        let mid = (lo + hi) * half;
+        let value = eval(coeffs, mid);
+
+        if value == zero {
+            return mid;
+        } else if (value > zero) == start_positive {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    (lo + hi) * half
+}
+
+fn find_roots<F: Float>(coeffs: &[F], start: F, end: F, depth: u32, roots: &mut Vec<F>) {
+    let zero = F::zero();
+    let half = F::from_f32(0.5).unwrap();
+
+    // A polynomial that's zero everywhere doesn't have any isolated roots.
+    if coeffs.iter().all(|c| *c == zero) {
+        return;
+    }
+
+    if coeffs[0] == zero {
+        roots.push(start);
+    }
+    if coeffs[coeffs.len() - 1] == zero {
+        roots.push(end);
+    }
+
+    match sign_changes(coeffs) {
+        0 => (),
+        1 if coeffs[0] != zero && coeffs[coeffs.len() - 1] != zero => {
+            roots.push(start + (end - start) * bisect_root(coeffs))
+        },
+        _ if depth >= MAX_DEPTH => roots.push((start + end) * half),
+        _ => {
+            let mid = (start + end) * half;
+            let (left, right) = bisect(coeffs);
+            find_roots(&left, start, mid, depth + 1, roots);
+            find_roots(&right, mid, end, depth + 1, roots);
+        }
+    }
+}
+
+/// Find the roots between `0.0` and `1.0` inclusive of the one-dimensional bezier polynomial with
+/// the coefficients `coeffs`. The returned roots are sorted, and roots closer together than the
+/// square root of `F`'s epsilon get merged.
+pub fn bernstein_roots<F: Float>(coeffs: &[F]) -> Vec<F> {
+    let mut roots = Vec::new();
+    if coeffs.is_empty() {
+        return roots;
+    }
+
+    find_roots(coeffs, F::zero(), F::one(), 0, &mut roots);
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let merge_dist = F::epsilon().sqrt();
+    roots.dedup_by(|a, b| *a - *b <= merge_dist);
+    roots
+}
@@ -0,0 +1,76 @@
+use alloc::vec::Vec;
+
+use super::{BezChain, Bez3o, Point, Float, lerp};
+
+impl<F, P> BezChain<F, Bez3o<F, P>, Vec<P>>
+        where F: Float,
+              P: Point<F> {
+    /// Convert the polyline through `points` into a chain of cubic curves, with one straight curve
+    /// for each segment of the polyline. Each curve's control points lie a third and two thirds of the
+    /// way along its segment, so the curve moves along the segment at a constant speed.
+    pub fn from_polyline(points: &[P]) -> BezChain<F, Bez3o<F, P>, Vec<P>> {
+        let third = F::one() / F::from_u32(3).unwrap();
+        let mut chain = Vec::with_capacity(points.len().saturating_sub(1) * 3 + 1);
+        chain.extend(points.first().cloned());
+
+        for w in points.windows(2) {
+            chain.push(lerp(w[0], w[1], third));
+            chain.push(lerp(w[0], w[1], third + third));
+            chain.push(w[1]);
+        }
+        BezChain::from_container(chain)
+    }
+
+    /// Convert a Catmull-Rom spline through `points` into a chain of cubic curves that passes through
+    /// every point, with one curve between each pair of consecutive points.
+    ///
+    /// The tangent at each point is parallel to the line between its neighbours, scaled by
+    /// `1 - tension`: a tension of `0.0` gives a standard Catmull-Rom spline, and a tension of `1.0`
+    /// gives straight lines between the points. The first and last points act as their own missing
+    /// neighbours.
+    pub fn from_catmull_rom(points: &[P], tension: F) -> BezChain<F, Bez3o<F, P>, Vec<P>> {
+        if points.len() < 2 {
+            return BezChain::from_container(points.to_vec());
+        }
+
+        let scale = (F::one() - tension) / F::from_u32(6).unwrap();
+        let last = points.len() - 1;
+        let mut chain = Vec::with_capacity(last * 3 + 1);
+        chain.push(points[0]);
+
+        for i in 0..last {
+            let prev = points[i.saturating_sub(1)];
+            let next = points[(i + 2).min(last)];
+            let (start, end) = (points[i], points[i + 1]);
+
+            chain.push(start + (end - prev) * scale);
+            chain.push(end - (next - start) * scale);
+            chain.push(end);
+        }
+        BezChain::from_container(chain)
+    }
+
+    /// Convert a uniform cubic B-spline with the control points `control` into the equivalent chain of
+    /// cubic curves. A B-spline with `n` control points gives `n - 3` curves, so there have to be at
+    /// least four control points for the chain to contain any curves.
+    pub fn from_bspline(control: &[P]) -> BezChain<F, Bez3o<F, P>, Vec<P>> {
+        if control.len() < 4 {
+            return BezChain::from_container(Vec::new());
+        }
+
+        let third = F::one() / F::from_u32(3).unwrap();
+        let sixth = F::one() / F::from_u32(6).unwrap();
+        let four = F::from_u32(4).unwrap();
+        // The point where the curves for each group of three consecutive control points meet.
+        let joint = |p: &[P]| (p[0] + p[1] * four + p[2]) * sixth;
+
+        let mut chain = Vec::with_capacity((control.len() - 3) * 3 + 1);
+        chain.push(joint(control));
+        for w in control.windows(4) {
+            chain.push(lerp(w[1], w[2], third));
+            chain.push(lerp(w[1], w[2], third + third));
+            chain.push(joint(&w[1..]));
+        }
+        BezChain::from_container(chain)
+    }
+}
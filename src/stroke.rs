@@ -0,0 +1,208 @@
+use alloc::vec::Vec;
+
+use super::{BezChain, BezCurve, BezCurve2d, Bez3o, ChainBuilder, NBez, OrderStatic, Point2d, Vector2d, Float};
+
+/// The shape of the ends of a stroke.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapStyle {
+    /// The stroke ends with a straight line across the end of the curve.
+    Butt,
+    /// The stroke ends with a semicircle around the end of the curve.
+    Round,
+    /// The stroke ends with a square that extends past the end of the curve by half of its width.
+    Square
+}
+
+/// The shape of the outside of the corners where the curves of a stroked chain meet.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JoinStyle<F: Float> {
+    /// The sides of the stroke get extended until they meet in a point. If the distance from that point
+    /// to the inside corner of the stroke would be more than the contained limit times the stroke's
+    /// width, the corner is bevelled instead, which stops sharp corners from making long spikes.
+    Miter(F),
+    /// The corner is rounded off with a circular arc around the joint.
+    Round,
+    /// The corner is cut off with a straight line.
+    Bevel
+}
+
+/// The sides of the stroke along one curve, with the curve's ends and the directions it leaves and
+/// enters them in.
+struct Segment<F: Float> {
+    left: Vec<NBez<F, Point2d<F>>>,
+    right: Vec<NBez<F, Point2d<F>>>,
+    start: Point2d<F>,
+    end: Point2d<F>,
+    start_tangent: Vector2d<F>,
+    end_tangent: Vector2d<F>
+}
+
+impl<F: Float> Segment<F> {
+    fn new<B>(curve: &B, half_width: F, tolerance: F) -> Segment<F>
+            where B: BezCurve<F, Point = Point2d<F>> {
+        Segment {
+            left: curve.offset(half_width, tolerance),
+            right: curve.offset(-half_width, tolerance),
+            start: curve.interp_unbounded(F::zero()),
+            end: curve.interp_unbounded(F::one()),
+            start_tangent: curve.tangent_unbounded(F::zero()),
+            end_tangent: curve.tangent_unbounded(F::one())
+        }
+    }
+
+    fn left_start(&self) -> Point2d<F> {
+        AsRef::<[Point2d<F>]>::as_ref(&self.left[0])[0]
+    }
+
+    fn right_end(&self) -> Point2d<F> {
+        AsRef::<[Point2d<F>]>::as_ref(&self.right[self.right.len() - 1])[3]
+    }
+}
+
+/// Add each of the cubic `pieces` to `builder`, in order.
+fn append_pieces<F: Float>(builder: &mut ChainBuilder<F, Point2d<F>>, pieces: &[NBez<F, Point2d<F>>]) {
+    for piece in pieces {
+        let p: &[Point2d<F>] = piece.as_ref();
+        builder.cubic_to(p[1], p[2], p[3]);
+    }
+}
+
+/// Add the reverse of each of the cubic `pieces` to `builder`, in reverse order.
+fn append_pieces_reversed<F: Float>(builder: &mut ChainBuilder<F, Point2d<F>>, pieces: &[NBez<F, Point2d<F>>]) {
+    for piece in pieces.iter().rev() {
+        let p: &[Point2d<F>] = piece.as_ref();
+        builder.cubic_to(p[2], p[1], p[0]);
+    }
+}
+
+/// Add a circular arc around `center` to `builder`, going `sweep` radians counter-clockwise from the
+/// current end of the chain and ending exactly on `to`.
+fn append_arc<F: Float>(builder: &mut ChainBuilder<F, Point2d<F>>, center: Point2d<F>, to: Point2d<F>, sweep: F) {
+    let from = Vector2d::from(builder.current() - center);
+    let arc = BezChain::arc(center, from.len(), from.angle(), sweep);
+    let curves: Vec<_> = arc.iter().collect();
+    for (i, curve) in curves.iter().enumerate() {
+        // End exactly on `to`, so that the rest of the outline joins up with the arc.
+        let end = if i == curves.len() - 1 {to} else {curve.end};
+        builder.cubic_to(curve.ctrl0, curve.ctrl1, end);
+    }
+}
+
+/// Add a cap around `center` to `builder`, going clockwise from the current end of the chain to `to`.
+/// Both ends of the cap are `half_width` from `center`.
+fn append_cap<F: Float>(builder: &mut ChainBuilder<F, Point2d<F>>, center: Point2d<F>, to: Point2d<F>, half_width: F, cap: CapStyle) {
+    match cap {
+        CapStyle::Butt => {
+            builder.line_to(to);
+        },
+        CapStyle::Round => append_arc(builder, center, to, -F::from_f64(::core::f64::consts::PI).unwrap()),
+        CapStyle::Square => {
+            // The direction the stroke leaves the cap in, which is the normal turned clockwise.
+            let normal = Vector2d::from(builder.current() - center).normalize();
+            let extent = -normal.perp() * half_width;
+            let corner = builder.current() + extent;
+            builder.line_to(corner)
+                   .line_to(to + extent)
+                   .line_to(to);
+        }
+    }
+}
+
+/// Add a join around the joint `center` to `builder`, going from the current end of the chain to `to`.
+/// `dir_in` is the direction the outline arrives at the join in, and `dir_out` is the direction it
+/// leaves in.
+fn append_join<F: Float>(builder: &mut ChainBuilder<F, Point2d<F>>, center: Point2d<F>, to: Point2d<F>, dir_in: Vector2d<F>, dir_out: Vector2d<F>, half_width: F, join: JoinStyle<F>) {
+    let from = builder.current();
+    let gap = Vector2d::from(to - from);
+    if gap.len() == F::zero() {
+        return;
+    }
+
+    // On the inside of the corner, the sides of the stroke overlap, so they can just be connected.
+    // On the outside, the sides stop short of each other and the gap between them needs filling.
+    if gap.dot(dir_in) <= F::zero() {
+        builder.line_to(to);
+        return;
+    }
+
+    match join {
+        JoinStyle::Bevel => {
+            builder.line_to(to);
+        },
+        JoinStyle::Round => {
+            let sweep = Vector2d::from(from - center).angle_between(Vector2d::from(to - center));
+            append_arc(builder, center, to, sweep);
+        },
+        JoinStyle::Miter(limit) => {
+            let denom = dir_in.cross(dir_out);
+            let miter = if denom == F::zero() {
+                None
+            } else {
+                Some(from + dir_in * (gap.cross(dir_out) / denom))
+            };
+
+            // The inside corner is as far from the joint as the miter is, on the other side of it.
+            match miter {
+                Some(miter) if Vector2d::from(miter - center).len() <= limit * half_width => {
+                    builder.line_to(miter)
+                           .line_to(to);
+                },
+                _ => {
+                    builder.line_to(to);
+                }
+            }
+        }
+    }
+}
+
+/// Build the closed outline of a stroke along `segments`, which must not be empty. The outline runs
+/// along the left side of the segments, around the end cap, back along the right side, and around the
+/// start cap.
+fn outline<F: Float>(segments: &[Segment<F>], half_width: F, join: JoinStyle<F>, cap: CapStyle) -> BezChain<F, Bez3o<F, Point2d<F>>, Vec<Point2d<F>>> {
+    let first = &segments[0];
+    let last = &segments[segments.len() - 1];
+
+    let mut builder = ChainBuilder::new(first.left_start());
+    for (i, segment) in segments.iter().enumerate() {
+        append_pieces(&mut builder, &segment.left);
+        if let Some(next) = segments.get(i + 1) {
+            append_join(&mut builder, segment.end, next.left_start(), segment.end_tangent, next.start_tangent, half_width, join);
+        }
+    }
+    append_cap(&mut builder, last.end, last.right_end(), half_width, cap);
+
+    for (i, segment) in segments.iter().enumerate().rev() {
+        append_pieces_reversed(&mut builder, &segment.right);
+        if i > 0 {
+            let prev = &segments[i - 1];
+            append_join(&mut builder, segment.start, prev.right_end(), -segment.start_tangent, -prev.end_tangent, half_width, join);
+        }
+    }
+    append_cap(&mut builder, first.start, first.left_start(), half_width, cap);
+    builder.build()
+}
+
+/// Get the closed outline of a stroke `width` wide along `curve`, with its ends capped by `cap`.
+pub(crate) fn stroke_curve<F, B>(curve: &B, width: F, cap: CapStyle, tolerance: F) -> BezChain<F, Bez3o<F, Point2d<F>>, Vec<Point2d<F>>>
+        where F: Float,
+              B: BezCurve<F, Point = Point2d<F>> {
+    let half_width = width / F::from_u32(2).unwrap();
+    outline(&[Segment::new(curve, half_width, tolerance)], half_width, JoinStyle::Bevel, cap)
+}
+
+impl<F, B, C> BezChain<F, B, C>
+        where F: Float,
+              B: BezCurve<F, Point = Point2d<F>> + OrderStatic,
+              C: AsRef<[Point2d<F>]> {
+    /// Get the closed outline of a stroke along the chain that's `width` wide, in the same way as
+    /// `BezCurve2d::stroke`. Where the chain's curves meet at an angle, the outside of the corner is
+    /// shaped by `join`. A chain without any curves has an empty outline.
+    pub fn stroke(&self, width: F, join: JoinStyle<F>, cap: CapStyle, tolerance: F) -> BezChain<F, Bez3o<F, Point2d<F>>, Vec<Point2d<F>>> {
+        let half_width = width / F::from_u32(2).unwrap();
+        let segments: Vec<_> = self.iter().map(|curve| Segment::new(&curve, half_width, tolerance)).collect();
+        if segments.is_empty() {
+            return BezChain::from_container(Vec::new());
+        }
+        outline(&segments, half_width, join, cap)
+    }
+}
@@ -0,0 +1,216 @@
+use core::fmt::Write;
+use core::iter::Peekable;
+use core::str::CharIndices;
+use alloc::vec::Vec;
+use alloc::string::String;
+use alloc::borrow::ToOwned;
+
+use super::{BezChain, Bez3o, Point2d, Float, BezError, lerp};
+
+/// Write `value` to `path`, with `decimals` digits after the decimal point if it isn't `None`.
+fn write_coord<F: Float>(path: &mut String, value: F, decimals: Option<usize>) {
+    let value = value.to_f64().unwrap();
+    match decimals {
+        Some(decimals) => write!(path, "{:.*}", decimals, value),
+        None => write!(path, "{}", value)
+    }.unwrap();
+}
+
+impl<F, C> BezChain<F, Bez3o<F, Point2d<F>>, C>
+        where F: Float,
+              C: AsRef<[Point2d<F>]> {
+    /// Create the data string of an SVG `path` element describing this chain, consisting of a moveto
+    /// command for the first point followed by a curveto command for each curve in the chain.
+    pub fn to_svg_path(&self) -> String {
+        self.svg_path(None)
+    }
+
+    /// Like `to_svg_path`, but formats every coordinate with `decimals` digits after the decimal point.
+    pub fn to_svg_path_prec(&self, decimals: usize) -> String {
+        self.svg_path(Some(decimals))
+    }
+
+    fn svg_path(&self, decimals: Option<usize>) -> String {
+        let mut path = String::new();
+
+        if let Some(start) = self.as_ref().as_ref().first() {
+            path.push('M');
+            write_coord(&mut path, start.x, decimals);
+            path.push(' ');
+            write_coord(&mut path, start.y, decimals);
+        }
+
+        for curve in self.iter() {
+            path.push_str(" C");
+            for (i, point) in [curve.ctrl0, curve.ctrl1, curve.end].iter().enumerate() {
+                if i != 0 {
+                    path.push(' ');
+                }
+                write_coord(&mut path, point.x, decimals);
+                path.push(' ');
+                write_coord(&mut path, point.y, decimals);
+            }
+        }
+
+        path
+    }
+}
+
+/// A token in an SVG path's data string.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    Command(char),
+    Number(f64)
+}
+
+/// Splits an SVG path's data string into commands and numbers.
+struct Tokenizer<'a> {
+    path: &'a str,
+    chars: Peekable<CharIndices<'a>>
+}
+
+impl<'a> Tokenizer<'a> {
+    fn new(path: &'a str) -> Tokenizer<'a> {
+        Tokenizer {
+            path,
+            chars: path.char_indices().peekable()
+        }
+    }
+
+    /// Consume characters for as long as `pred` returns true, returning the index after the last one.
+    fn skip_while<P: Fn(char) -> bool>(&mut self, pred: P) -> usize {
+        while let Some(&(i, c)) = self.chars.peek() {
+            if !pred(c) {
+                return i;
+            }
+            self.chars.next();
+        }
+        self.path.len()
+    }
+
+    fn number(&mut self, start: usize) -> Result<Token, BezError> {
+        if let Some(&(_, '+')) | Some(&(_, '-')) = self.chars.peek() {
+            self.chars.next();
+        }
+        let mut end = self.skip_while(|c| c.is_ascii_digit());
+        if let Some(&(_, '.')) = self.chars.peek() {
+            self.chars.next();
+            end = self.skip_while(|c| c.is_ascii_digit());
+        }
+        if let Some(&(_, 'e')) | Some(&(_, 'E')) = self.chars.peek() {
+            self.chars.next();
+            if let Some(&(_, '+')) | Some(&(_, '-')) = self.chars.peek() {
+                self.chars.next();
+            }
+            end = self.skip_while(|c| c.is_ascii_digit());
+        }
+
+        let number = &self.path[start..end];
+        number.parse()
+            .map(Token::Number)
+            .map_err(|_| BezError::ParseError(format!("invalid number \"{}\" at index {}", number, start)))
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Result<Token, BezError>;
+
+    fn next(&mut self) -> Option<Result<Token, BezError>> {
+        self.skip_while(|c| c.is_whitespace() || c == ',');
+
+        self.chars.peek().cloned().map(|(i, c)| {
+            if c.is_ascii_alphabetic() && c != 'e' && c != 'E' {
+                self.chars.next();
+                Ok(Token::Command(c))
+            } else {
+                self.number(i)
+            }
+        })
+    }
+}
+
+impl<F> BezChain<F, Bez3o<F, Point2d<F>>, Vec<Point2d<F>>>
+        where F: Float {
+    /// Parse the data string of an SVG `path` element into a chain of cubic curves.
+    ///
+    /// The moveto (`M`), lineto (`L`), curveto (`C`), and closepath (`Z`) commands are supported, in
+    /// both their absolute and relative forms. Lines become cubic curves with their control points
+    /// evenly spaced along the line. As a chain is one continuous path, a moveto is only allowed at the
+    /// start of the path.
+    pub fn from_svg_path(path: &str) -> Result<Self, BezError> {
+        let mut tokens = Tokenizer::new(path).peekable();
+        let mut points: Vec<Point2d<F>> = Vec::new();
+        let mut command = None;
+
+        let third = F::one() / F::from_u32(3).unwrap();
+        let two_thirds = third + third;
+
+        while let Some(token) = tokens.peek().cloned() {
+            // Numbers following a command's arguments repeat the command.
+            let c = match token? {
+                Token::Command(c) => {
+                    tokens.next();
+                    c
+                },
+                Token::Number(_) => command.ok_or_else(|| {
+                    BezError::ParseError("coordinates must follow a command".to_owned())
+                })?
+            };
+            command = Some(c);
+
+            let origin = Point2d::new(F::zero(), F::zero());
+            let current = points.last().cloned().unwrap_or(origin);
+            let offset = if c.is_ascii_lowercase() {current} else {origin};
+
+            let mut read_point = || -> Result<Point2d<F>, BezError> {
+                let mut coords = [F::zero(); 2];
+                for coord in coords.iter_mut() {
+                    *coord = match tokens.next() {
+                        Some(Ok(Token::Number(n))) => F::from_f64(n).unwrap(),
+                        Some(Err(e)) => return Err(e),
+                        _ => return Err(BezError::ParseError(format!("missing coordinate for \"{}\" command", c)))
+                    };
+                }
+                Ok(Point2d::new(coords[0] + offset.x, coords[1] + offset.y))
+            };
+
+            match c {
+                'M' | 'm' => {
+                    if !points.is_empty() {
+                        return Err(BezError::ParseError("paths with multiple subpaths are unsupported".to_owned()));
+                    }
+                    points.push(read_point()?);
+                    // Coordinates following a moveto are implicit linetos.
+                    command = Some(if c == 'M' {'L'} else {'l'});
+                },
+                'L' | 'l' if !points.is_empty() => {
+                    let end = read_point()?;
+                    points.push(lerp(current, end, third));
+                    points.push(lerp(current, end, two_thirds));
+                    points.push(end);
+                },
+                'C' | 'c' if !points.is_empty() => {
+                    let ctrl0 = read_point()?;
+                    let ctrl1 = read_point()?;
+                    let end = read_point()?;
+                    points.extend_from_slice(&[ctrl0, ctrl1, end]);
+                },
+                'Z' | 'z' if !points.is_empty() => {
+                    let start = points[0];
+                    if current != start {
+                        points.push(lerp(current, start, third));
+                        points.push(lerp(current, start, two_thirds));
+                        points.push(start);
+                    }
+                    command = None;
+                },
+                'L' | 'l' | 'C' | 'c' | 'Z' | 'z' => {
+                    return Err(BezError::ParseError("path data must begin with a moveto command".to_owned()))
+                },
+                _ => return Err(BezError::ParseError(format!("unsupported command \"{}\"", c)))
+            }
+        }
+
+        Ok(BezChain::from_container(points))
+    }
+}
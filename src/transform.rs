@@ -0,0 +1,70 @@
+use core::ops::Mul;
+
+use super::{Float, Point2d, Vector2d};
+
+/// A 2-dimensional affine transformation, stored as the top two rows of a 3x3 matrix. Points are
+/// transformed by treating them as column vectors with an implicit third component of one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AffineMatrix2d<F: Float> {
+    pub rows: [[F; 3]; 2]
+}
+
+impl<F: Float> AffineMatrix2d<F> {
+    /// Create a matrix from its two rows.
+    pub fn new(rows: [[F; 3]; 2]) -> AffineMatrix2d<F> {
+        AffineMatrix2d {
+            rows
+        }
+    }
+
+    /// Create a matrix that leaves points unchanged.
+    pub fn identity() -> AffineMatrix2d<F> {
+        AffineMatrix2d::scale(F::one(), F::one())
+    }
+
+    /// Create a matrix that moves points by `offset`.
+    pub fn translation(offset: Vector2d<F>) -> AffineMatrix2d<F> {
+        let (zero, one) = (F::zero(), F::one());
+        AffineMatrix2d::new([[one, zero, offset.x], [zero, one, offset.y]])
+    }
+
+    /// Create a matrix that rotates points counter-clockwise around the origin by `angle` radians.
+    pub fn rotation(angle: F) -> AffineMatrix2d<F> {
+        let (sin, cos) = angle.sin_cos();
+        AffineMatrix2d::new([[cos, -sin, F::zero()], [sin, cos, F::zero()]])
+    }
+
+    /// Create a matrix that scales points away from the origin by `x` horizontally and `y` vertically.
+    pub fn scale(x: F, y: F) -> AffineMatrix2d<F> {
+        let zero = F::zero();
+        AffineMatrix2d::new([[x, zero, zero], [zero, y, zero]])
+    }
+
+    /// Create a matrix that shears points, offsetting their x component by `x` times their y component
+    /// and their y component by `y` times their x component.
+    pub fn shear(x: F, y: F) -> AffineMatrix2d<F> {
+        let (zero, one) = (F::zero(), F::one());
+        AffineMatrix2d::new([[one, x, zero], [y, one, zero]])
+    }
+
+    /// Apply the transformation to `p`.
+    pub fn transform_point(&self, p: Point2d<F>) -> Point2d<F> {
+        let [r0, r1] = self.rows;
+        Point2d::new(r0[0] * p.x + r0[1] * p.y + r0[2],
+                     r1[0] * p.x + r1[1] * p.y + r1[2])
+    }
+}
+
+/// Composes two transformations. The resulting matrix applies `rhs` first, then `self`.
+impl<F: Float> Mul for AffineMatrix2d<F> {
+    type Output = AffineMatrix2d<F>;
+
+    fn mul(self, rhs: AffineMatrix2d<F>) -> AffineMatrix2d<F> {
+        let [a0, a1] = self.rows;
+        let [b0, b1] = rhs.rows;
+        let row = |a: [F; 3]| [a[0] * b0[0] + a[1] * b1[0],
+                               a[0] * b0[1] + a[1] * b1[1],
+                               a[0] * b0[2] + a[1] * b1[2] + a[2]];
+        AffineMatrix2d::new([row(a0), row(a1)])
+    }
+}
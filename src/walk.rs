@@ -0,0 +1,98 @@
+use alloc::vec::Vec;
+
+use super::{BezChain, BezCurve, BezCurve2d, BezIter, OrderStatic, Point2d, Vector2d, Float};
+use super::{arc_length_table, arc_length_param};
+
+/// An iterator that walks along a chain, yielding points that are a fixed distance apart along the
+/// chain's length. Created by `BezChain::walk` and `BezChain::walk_with_end`.
+pub struct ChainWalk<'a, F, B>
+        where F: Float,
+              B: BezCurve<F, Point = Point2d<F>> + OrderStatic {
+    curves: BezIter<'a, F, B>,
+    step: F,
+    tolerance: F,
+    include_end: bool,
+    /// The curve currently being walked along, along with its arc length table.
+    curve: Option<(B, Vec<(F, F)>)>,
+    /// The index of the table entry that ends the piece the next point lies in.
+    entry: usize,
+    /// How far along the current curve the next point lies.
+    dist: F
+}
+
+impl<'a, F, B> Iterator for ChainWalk<'a, F, B>
+        where F: Float,
+              B: BezCurve<F, Point = Point2d<F>> + OrderStatic {
+    type Item = (Point2d<F>, Vector2d<F>);
+
+    fn next(&mut self) -> Option<(Point2d<F>, Vector2d<F>)> {
+        loop {
+            if self.curve.is_none() {
+                let curve = self.curves.next()?;
+                let mut table = vec![(F::zero(), F::zero())];
+                arc_length_table(&curve, F::zero(), F::one(), self.tolerance, 0, &mut table);
+                self.curve = Some((curve, table));
+                self.entry = 1;
+            }
+
+            let (length, at_last_curve) = {
+                let (ref curve, ref table) = *self.curve.as_ref().unwrap();
+                let length = table[table.len() - 1].1;
+
+                if self.dist <= length {
+                    while self.entry < table.len() - 1 && table[self.entry].1 < self.dist {
+                        self.entry += 1;
+                    }
+                    let t = arc_length_param(curve, table[self.entry - 1], table[self.entry].0, self.dist);
+                    self.dist = self.dist + self.step;
+                    return Some((curve.interp_unbounded(t), curve.tangent_unbounded(t)));
+                }
+                (length, self.curves.len() == 0)
+            };
+
+            // The last partial step gets cut short at the end of the chain, unless it's short enough
+            // that the previous point was already the end.
+            if at_last_curve && self.include_end && self.dist - length < self.step - self.tolerance {
+                let (curve, _) = self.curve.take().unwrap();
+                self.include_end = false;
+                return Some((curve.interp_unbounded(F::one()), curve.tangent_unbounded(F::one())));
+            }
+
+            self.dist = self.dist - length;
+            self.curve = None;
+        }
+    }
+}
+
+impl<F, B, C> BezChain<F, B, C>
+        where F: Float,
+              B: BezCurve<F, Point = Point2d<F>> + OrderStatic,
+              C: AsRef<[Point2d<F>]> {
+    /// Walk along the entire chain, yielding a point and the unit tangent at that point every `step`
+    /// units of length, starting at the start of the chain. `tolerance` is how accurately the length of
+    /// each curve gets measured. If the chain's length isn't a multiple of `step`, the final partial
+    /// step is left out.
+    ///
+    /// Panics if `step` isn't positive.
+    pub fn walk(&self, step: F, tolerance: F) -> ChainWalk<'_, F, B> {
+        assert!(step > F::zero(), "walk step must be positive");
+        ChainWalk {
+            curves: self.iter(),
+            step,
+            tolerance,
+            include_end: false,
+            curve: None,
+            entry: 1,
+            dist: F::zero()
+        }
+    }
+
+    /// Like `walk`, but if the chain's length isn't a multiple of `step`, the end of the chain is
+    /// yielded after the final partial step.
+    pub fn walk_with_end(&self, step: F, tolerance: F) -> ChainWalk<'_, F, B> {
+        ChainWalk {
+            include_end: true,
+            ..self.walk(step, tolerance)
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use alloc::vec::Vec;
+
+use super::{BezChain, BezCurve, OrderStatic, Point2d, Float};
+use super::roots;
+
+/// The rule used to decide which points are inside of a closed shape, given how many times the shape's
+/// outline winds around them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillRule {
+    /// Points are inside if the outline crosses a ray from them an odd number of times.
+    EvenOdd,
+    /// Points are inside if the outline winds around them a nonzero number of times.
+    NonZero
+}
+
+impl<F, B, C> BezChain<F, B, C>
+        where F: Float,
+              B: BezCurve<F, Point = Point2d<F>> + OrderStatic,
+              C: AsRef<[Point2d<F>]> {
+    /// Get the number of times the chain winds counter-clockwise around `p`, with clockwise windings
+    /// counting as negative. The chain is assumed to be closed, with its last point being its first.
+    ///
+    /// This is found by casting a ray from `p` in the positive x direction and counting how many times
+    /// the chain crosses it upwards and downwards. Points at the height of the ray count as being above
+    /// it, so a chain that passes through the ray at a vertex or runs along it only counts as crossing
+    /// it if it actually goes from one side to the other.
+    pub fn winding_number(&self, p: Point2d<F>) -> i32 {
+        let half = F::from_f32(0.5).unwrap();
+        let mut winding = 0;
+
+        for curve in self.iter() {
+            let points = AsRef::<[Point2d<F>]>::as_ref(&curve);
+            let coeffs: Vec<F> = points.iter().map(|c| c.y - p.y).collect();
+            let above = |t: F| roots::eval(&coeffs, t) >= F::zero();
+
+            // The curve's side of the ray can only change at a root. The sides at the ends come
+            // straight from the end points, so the curves sharing a point agree on its side.
+            let mut breaks = roots::bernstein_roots(&coeffs);
+            if breaks.first() != Some(&F::zero()) {
+                breaks.insert(0, F::zero());
+            }
+            if breaks.last() != Some(&F::one()) {
+                breaks.push(F::one());
+            }
+
+            let mut sides = vec![coeffs[0] >= F::zero()];
+            sides.extend(breaks.windows(2).map(|w| above((w[0] + w[1]) * half)));
+            sides.push(coeffs[coeffs.len() - 1] >= F::zero());
+
+            for (&t, side) in breaks.iter().zip(sides.windows(2)) {
+                if side[0] == side[1] || curve.interp_unbounded(t).x <= p.x {
+                    continue;
+                }
+                winding += if side[1] {1} else {-1};
+            }
+        }
+        winding
+    }
+
+    /// Test whether `p` is inside of the closed shape outlined by the chain, according to `rule`. The
+    /// chain is assumed to be closed, with its last point being its first.
+    pub fn contains(&self, p: Point2d<F>, rule: FillRule) -> bool {
+        let winding = self.winding_number(p);
+        match rule {
+            FillRule::EvenOdd => winding % 2 != 0,
+            FillRule::NonZero => winding != 0
+        }
+    }
+}